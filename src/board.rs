@@ -1,67 +1,104 @@
-use crate::{Card, Stack};
+use crate::{Card, Configuration, Error, Stack};
 use itertools::Itertools;
 use std::slice::{Iter, IterMut};
+use std::str::FromStr;
 use std::{fmt, ops};
 
-/// A type representing all of the stacks in the board.
+/// An index of one of the stacks on the board.
 ///
-/// The board consists of four stacks, `A`, `B`, `C`, and `D`. These are
-/// accessed by indexing the board with the appropriate variant of this
-/// enum. For example `board[StackId::A]`. You can also iterate over the
-/// stacks on the board with `for stack in &board ...`.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-pub enum StackId {
-    A,
-    B,
-    C,
-    D,
-}
+/// The standard board consists of four stacks, `A`, `B`, `C`, and `D`,
+/// though a board may have any number of stacks. Stacks are accessed by
+/// indexing the board with the appropriate id. For example
+/// `board[StackId::A]`. You can also iterate over the stacks on the board
+/// with `for stack in &board ...`.
+///
+/// Stack ids are displayed as letters, `A` for the first stack, `B` for
+/// the second, and so on.
+///
+/// # Examples
+///
+/// ```
+/// use take5::StackId;
+///
+/// assert_eq!(StackId::new(2), StackId::C);
+/// assert_eq!(StackId::C.index(), 2);
+/// assert_eq!(StackId::new(4).to_string(), "E");
+/// assert_eq!("e".parse::<StackId>(), Ok(StackId::new(4)));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct StackId(usize);
 
 impl StackId {
-    /// Returns a static array of the variants of `StackId`. This is mainly
-    /// useful for iterating over the possible stacks.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use take5::{Card, Board, StackId};
-    ///
-    /// let mut deck = Card::deck(|_| 3);
-    ///
-    /// // Setup a board.
-    /// let mut board = Board::default();
-    /// for stack in &mut board {
-    ///     stack.push(deck.pop().unwrap());
-    /// }
-    ///
-    /// // Print each stack in the board.
-    /// for stack in &mut board {
-    ///     println!("{:?}", stack);
-    /// }
-    /// ```
-    pub fn all() -> [StackId; 4] {
-        [StackId::A, StackId::B, StackId::C, StackId::D]
+    /// The first stack.
+    pub const A: StackId = StackId(0);
+    /// The second stack.
+    pub const B: StackId = StackId(1);
+    /// The third stack.
+    pub const C: StackId = StackId(2);
+    /// The fourth stack.
+    pub const D: StackId = StackId(3);
+
+    /// Returns the id of the stack at the given index.
+    pub fn new(index: usize) -> Self {
+        StackId(index)
+    }
+
+    /// Returns the index of this stack on the board.
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+impl fmt::Display for StackId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        if self.0 < 26 {
+            write!(f, "{}", (b'A' + self.0 as u8) as char)
+        } else {
+            write!(f, "{}", self.0 + 1)
+        }
+    }
+}
+
+impl FromStr for StackId {
+    type Err = String;
+
+    /// Parses a stack's letter, or its one based number for stacks
+    /// past `Z`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) if c.is_ascii_alphabetic() => {
+                Ok(StackId((c.to_ascii_uppercase() as u8 - b'A') as usize))
+            }
+            _ => match s.parse::<usize>() {
+                Ok(n) if n > 0 => Ok(StackId(n - 1)),
+                _ => Err(format!("invalid stack id `{}`", s)),
+            },
+        }
     }
 }
 
-/// A board represents the playing field of take5, with 4 stacks of cards.
+/// A board represents the playing field of take5, with rows of stacks of
+/// cards.
 ///
-/// The best way to think of the board is as an array of four stacks.
-/// Access to the stacks is done via indexing with `StackId`s, and stacks
-/// act much other collections, containing cards.
+/// The best way to think of the board is as an array of stacks, four of
+/// them by default. Access to the stacks is done via indexing with
+/// `StackId`s, and stacks act much other collections, containing cards.
 ///
 /// # Examples
 ///
 /// ```
-/// use take5::{Board, Card};
+/// use take5::{Board, Card, Configuration};
 ///
-/// let mut deck = Card::deck(|_| 3);
+/// let config = Configuration::default();
+/// let mut deck = Card::deck(&config, |_| 3);
 /// let mut board = Board::default();
 ///
 /// // Push two cards onto each stack.
 /// for stack in &mut board {
-///     stack.push(deck.remove(0));
-///     stack.push(deck.remove(0));
+///     stack.push(deck.remove(0), &config);
+///     stack.push(deck.remove(0), &config);
 /// }
 ///
 /// // Print the stacks total bull value.
@@ -69,48 +106,99 @@ impl StackId {
 ///     println!("{}", stack.bulls());
 /// }
 /// ```
-#[derive(Debug, Serialize, Deserialize)]
-pub struct Board([Stack; 4]);
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Board(Vec<Stack>);
 
 impl Board {
+    /// Creates a board with `stack_count` empty stacks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use take5::Board;
+    ///
+    /// let board = Board::new(5);
+    /// assert_eq!(board.len(), 5);
+    /// ```
+    pub fn new(stack_count: usize) -> Self {
+        Board(vec![Stack::default(); stack_count])
+    }
+
+    /// Returns the number of stacks on this board.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true when this board has no stacks.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the ids of every stack on this board, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use take5::{Card, Board, Configuration, StackId};
+    ///
+    /// let config = Configuration::default();
+    /// let mut deck = Card::deck(&config, |_| 3);
+    ///
+    /// // Setup a board.
+    /// let mut board = Board::new(3);
+    /// for stack in &mut board {
+    ///     stack.push(deck.pop().unwrap(), &config);
+    /// }
+    ///
+    /// // Print each stack in the board.
+    /// for id in board.ids() {
+    ///     println!("{}: {}", id, board[id]);
+    /// }
+    /// assert_eq!(board.ids().last(), Some(StackId::C));
+    /// ```
+    pub fn ids(&self) -> impl Iterator<Item = StackId> {
+        (0..self.0.len()).map(StackId)
+    }
+
+    /// Returns the stack with the given id, or `None` if the board has no
+    /// such stack.
+    pub fn get(&self, id: StackId) -> Option<&Stack> {
+        self.0.get(id.0)
+    }
+
     /// Returns the id of the stack which has the largest face value,
     /// smaller than the given card. If there is no such card this
     /// function returns `None`.
     ///
+    /// # Errors
+    ///
+    /// Returns `Error::EmptyStack` if a stack on this board has no cards.
+    ///
     /// # Examples
     ///
     /// ```
-    /// use take5::{Card, Board, StackId};
+    /// use take5::{Card, Board, Configuration, StackId};
     ///
-    /// let mut deck = Card::deck(|_| 3);
+    /// let config = Configuration::default();
+    /// let mut deck = Card::deck(&config, |_| 3);
     /// let mut board = Board::default();
     /// for stack in &mut board {
-    ///     stack.push(deck.remove(1));
+    ///     stack.push(deck.remove(1), &config);
     /// }
     /// let card0 = deck.remove(0);
     /// let card1 = deck.remove(0);
-    /// assert_eq!(board.closest_smaller(&card0), None);
-    /// assert_eq!(board.closest_smaller(&card1), Some(StackId::D));
+    /// assert_eq!(board.closest_smaller(&card0), Ok(None));
+    /// assert_eq!(board.closest_smaller(&card1), Ok(Some(StackId::D)));
     /// ```
-    pub fn closest_smaller(&self, card: &Card) -> Option<StackId> {
+    pub fn closest_smaller(&self, card: &Card) -> Result<Option<StackId>, Error> {
         let mut larger_than = Vec::new();
-        for id in StackId::all().iter() {
-            let last = self[*id].last().expect("stack doesn't have any cards.");
+        for id in self.ids() {
+            let last = self[id].last().ok_or(Error::EmptyStack(id))?;
             if card.face() > last.face() {
-                larger_than.push((*id, last.face()));
+                larger_than.push((id, last.face()));
             }
         }
-        if larger_than.len() > 0 {
-            larger_than.sort_by(|a, b| a.1.cmp(&b.1));
-            Some(
-                larger_than
-                    .last()
-                    .expect("larger_than must have an element.")
-                    .0,
-            )
-        } else {
-            None
-        }
+        Ok(larger_than.into_iter().max_by_key(|a| a.1).map(|a| a.0))
     }
 
     /// Clears the board, removing all cards from all stacks.
@@ -118,14 +206,15 @@ impl Board {
     /// # Examples
     ///
     /// ```
-    /// use take5::{Card, Board, StackId};
+    /// use take5::{Card, Board, Configuration, StackId};
     ///
-    /// let mut deck = Card::deck(|_| 3);
+    /// let config = Configuration::default();
+    /// let mut deck = Card::deck(&config, |_| 3);
     ///
     /// // Setup a board.
     /// let mut board = Board::default();
     /// for stack in &mut board {
-    ///     stack.push(deck.remove(1));
+    ///     stack.push(deck.remove(1), &config);
     /// }
     /// assert!(!board[StackId::A].is_empty());
     /// board.clear();
@@ -136,25 +225,11 @@ impl Board {
             stack.clear();
         }
     }
-
-    fn id(id: StackId) -> usize {
-        match id {
-            StackId::A => 0,
-            StackId::B => 1,
-            StackId::C => 2,
-            StackId::D => 3,
-        }
-    }
 }
 
 impl Default for Board {
     fn default() -> Self {
-        Board([
-            Stack::default(),
-            Stack::default(),
-            Stack::default(),
-            Stack::default(),
-        ])
+        Board::new(Configuration::stack_count())
     }
 }
 
@@ -162,13 +237,13 @@ impl ops::Index<StackId> for Board {
     type Output = Stack;
 
     fn index(&self, index: StackId) -> &Stack {
-        &self.0[Board::id(index)]
+        &self.0[index.0]
     }
 }
 
 impl ops::IndexMut<StackId> for Board {
     fn index_mut(&mut self, index: StackId) -> &mut Stack {
-        &mut self.0[Board::id(index)]
+        &mut self.0[index.0]
     }
 }
 
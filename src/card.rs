@@ -1,4 +1,4 @@
-use crate::Configuration;
+use crate::{Configuration, Error};
 use std::fmt;
 
 /// Playing cards for the game.
@@ -8,32 +8,73 @@ use std::fmt;
 /// deck.
 ///
 /// Cards in take5 have two properties, the `face` and the `bull`. The face
-/// value is a number between 1 and 104 inclusive. This number uniquely
+/// value is a number between 1 and the configured `deck_size` inclusive,
+/// which is 104 for a standard deck. This number uniquely
 /// identifies the card. The bull value is the number of points (remember
 /// points are bad) that a card is worth.
 ///
 /// # Examples
 ///
 /// ```
-/// use take5::Card;
+/// use take5::{Card, Configuration};
 ///
-/// let deck = Card::deck(|_| 3);
+/// let deck = Card::deck(&Configuration::default(), |_| 3);
 /// println!("{:?}", deck[10]);
 /// ```
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Serialize, Deserialize)]
-pub struct Card(u8, u8);
+pub struct Card(u16, u8);
 
 impl Card {
-    pub fn new(f: u8, b: u8) -> Self {
+    pub fn new(f: u16, b: u8) -> Self {
         Card(f, b)
     }
-    /// Given a function mapping face values to bull values,
-    /// returns all 104 cards of the take5 deck.
+    /// Given a function mapping face values to bull values, returns all
+    /// `config.deck_size` cards of the take5 deck.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::BullRange` for the first card which would have a
+    /// bull value not in the range of `config.bull_range`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use take5::{Card, Configuration, Error};
+    ///
+    /// let config = Configuration { bull_range: (3, 7), ..Configuration::default() };
+    /// assert!(Card::try_deck(&config, |_| 3).is_ok());
+    /// assert_eq!(
+    ///     Card::try_deck(&config, |face| if face < 10 { 3 } else { 1 }),
+    ///     Err(Error::BullRange { face: 10, bull: 1, range: (3, 7) }),
+    /// );
+    /// ```
+    pub fn try_deck<F>(config: &Configuration, f: F) -> Result<Vec<Card>, Error>
+    where
+        F: Fn(u16) -> u8,
+    {
+        let (lower, upper) = config.bull_range;
+        (1..=config.deck_size)
+            .map(|face| {
+                let bull = f(face);
+                if bull < lower || bull > upper {
+                    return Err(Error::BullRange {
+                        face,
+                        bull,
+                        range: config.bull_range,
+                    });
+                }
+                Ok(Card(face, bull))
+            })
+            .collect()
+    }
+
+    /// Given a function mapping face values to bull values, returns all
+    /// `config.deck_size` cards of the take5 deck, as `try_deck`.
     ///
     /// # Panics
     ///
     /// This function panics if a card would have a bull value not in the
-    /// range of `Configuration::bull_range()`.
+    /// range of `config.bull_range`.
     ///
     /// # Examples
     ///
@@ -42,29 +83,22 @@ impl Card {
     ///
     /// // A deck who's bull values are 2 for the lower half the deck and
     /// // three for the higher half.
-    /// let deck = Card::deck(|face| if face < 52 { 4 } else { 3 });
-    /// assert_eq!(deck.len(), Configuration::deck_size() as usize);
+    /// let config = Configuration::default();
+    /// let deck = Card::deck(&config, |face| if face < 52 { 4 } else { 3 });
+    /// assert_eq!(deck.len(), config.deck_size as usize);
     /// assert_eq!(deck[30].bull(), 4);
     /// assert_eq!(deck[70].bull(), 3);
+    ///
+    /// // Decks aren't limited to 255 cards.
+    /// let config = Configuration { deck_size: 304, ..Configuration::default() };
+    /// let deck = Card::deck(&config, |_| 2);
+    /// assert_eq!(deck.last().unwrap().face(), 304);
     /// ```
-    pub fn deck<F>(f: F) -> Vec<Card>
+    pub fn deck<F>(config: &Configuration, f: F) -> Vec<Card>
     where
-        F: Fn(u8) -> u8,
+        F: Fn(u16) -> u8,
     {
-        (1..Configuration::deck_size() + 1)
-            .map(|i| {
-                let bull = f(i);
-                if bull < Configuration::bull_range().0 || bull > Configuration::bull_range().1 {
-                    panic!(
-                        "Bull values must be in range [{}..{}] was {}.",
-                        Configuration::bull_range().0,
-                        Configuration::bull_range().1,
-                        bull
-                    );
-                }
-                Card(i, bull)
-            })
-            .collect()
+        Card::try_deck(config, f).unwrap_or_else(|e| panic!("{}", e))
     }
 
     /// Returns the face value of this card.
@@ -72,12 +106,12 @@ impl Card {
     /// # Examples
     ///
     /// ```
-    /// use take5::Card;
+    /// use take5::{Card, Configuration};
     ///
-    /// let deck = Card::deck(|_| 3);
+    /// let deck = Card::deck(&Configuration::default(), |_| 3);
     /// assert_eq!(deck[0].face(), 1);
     /// ```
-    pub fn face(&self) -> u8 {
+    pub fn face(&self) -> u16 {
         self.0
     }
 
@@ -86,9 +120,9 @@ impl Card {
     /// # Examples
     ///
     /// ```
-    /// use take5::Card;
+    /// use take5::{Card, Configuration};
     ///
-    /// let deck = Card::deck(|_| 3);
+    /// let deck = Card::deck(&Configuration::default(), |_| 3);
     /// assert_eq!(deck[54].face(), 55);
     /// assert_eq!(deck[54].bull(), 3);
     /// ```
@@ -0,0 +1,372 @@
+use crate::player::{Id, PlayerError, PlayerView};
+use crate::{Board, Card, GameResult, Player, StackId};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::time::{Duration, Instant};
+use std::{cmp, fmt, mem, thread};
+
+/// Time limits on the players' decisions, for use in a `Configuration`.
+///
+/// Limits are given in milliseconds, and apply to the plays and choices a
+/// game asks its players for, see `Game::ask_players`. A player who runs
+/// out of time has their decision made for them by the `fallback`, and
+/// the game goes on without waiting for them. A late answer is thrown
+/// away, and the player is caught up on the game before their next
+/// decision.
+///
+/// In configuration files this is written as an object with any of the
+/// fields, e.g. `{ "decision" : 1000, "game" : 60000 }`.
+///
+/// # Examples
+///
+/// ```
+/// use take5::{Configuration, Fallback, Game, Player, TimeLimit};
+/// use take5::dealer::StandardDealer;
+/// use take5::player::AiPlayer;
+///
+/// let config = Configuration {
+///     time_limit: TimeLimit {
+///         decision: Some(1000),
+///         game: Some(60_000),
+///         fallback: Fallback::Lowest,
+///     },
+///     ..Configuration::default()
+/// };
+/// let players = (0..3).map(|id| {
+///     Box::new(AiPlayer::new(id)) as Box<dyn Player>
+/// }).collect();
+/// let mut game = Game::with_configuration(config, Box::new(StandardDealer::default()), players);
+/// let result = game.run();
+/// for standing in &result.standings {
+///     assert!(standing.time.unwrap().as_secs() < 60);
+/// }
+/// ```
+///
+/// A player who never answers doesn't hold up the game.
+///
+/// ```
+/// use std::{fmt, thread};
+/// use std::time::Duration;
+/// use take5::{Board, Card, Configuration, Game, Player, StackId, TimeLimit};
+/// use take5::dealer::StandardDealer;
+/// use take5::player::{AiPlayer, Id, PlayerError, PlayerView};
+///
+/// struct Stuck(AiPlayer);
+///
+/// impl Player for Stuck {
+///     fn id(&self) -> Id { self.0.id() }
+///     fn hand(&self) -> &[Card] { self.0.hand() }
+///     fn pile(&self) -> &[Card] { self.0.pile() }
+///     fn draw(&mut self, card: Card) { self.0.draw(card) }
+///     fn discard(&mut self, card: &Card) { self.0.discard(card) }
+///     fn look_at_board(&mut self, board: &Board) { self.0.look_at_board(board) }
+///     fn look_at_played(&mut self, played: &[(Card, Id)]) { self.0.look_at_played(played) }
+///     fn look_at_taken(&mut self, taken: (&[Card], Id)) { self.0.look_at_taken(taken) }
+///     fn take(&mut self, cards: Vec<Card>) { self.0.take(cards) }
+///     fn choose(&self, card: &Card, board: &Board, view: &PlayerView) -> Result<StackId, PlayerError> {
+///         self.0.choose(card, board, view)
+///     }
+///     fn play(&mut self, view: &PlayerView) -> Result<Card, PlayerError> {
+///         thread::sleep(Duration::from_secs(3600));
+///         self.0.play(view)
+///     }
+/// }
+///
+/// impl fmt::Display for Stuck {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "Stuck") }
+/// }
+///
+/// let config = Configuration {
+///     turn_count: 3,
+///     time_limit: TimeLimit { game: Some(50), ..TimeLimit::default() },
+///     ..Configuration::default()
+/// };
+/// let players = vec![
+///     Box::new(Stuck(AiPlayer::new(0))) as Box<dyn Player>,
+///     Box::new(AiPlayer::new(1)) as Box<dyn Player>,
+/// ];
+/// let mut game = Game::with_configuration(config, Box::new(StandardDealer::default()), players);
+/// let result = game.run();
+/// let stuck = result.standings.iter().find(|s| s.id == 0).unwrap();
+/// assert_eq!(stuck.time, Some(Duration::from_millis(50)));
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeLimit {
+    /// The milliseconds a player has for each decision.
+    #[serde(default)]
+    pub decision: Option<u64>,
+    /// The milliseconds a player has for all of their decisions over the
+    /// game, like a chess clock. Once they're used up, every later
+    /// decision is made by the fallback.
+    #[serde(default)]
+    pub game: Option<u64>,
+    /// How a decision is made for a player who runs out of time.
+    #[serde(default)]
+    pub fallback: Fallback,
+}
+
+impl TimeLimit {
+    /// Returns true when any limit is set.
+    pub fn is_limited(&self) -> bool {
+        self.decision.is_some() || self.game.is_some()
+    }
+}
+
+/// How a decision is made for a player who runs out of time.
+///
+/// In configuration files this is written as `"ai"`, `"lowest"` or
+/// `"random"`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Fallback {
+    /// An `AiPlayer` holding the player's hand decides.
+    #[default]
+    Ai,
+    /// The lowest card in the player's hand is played, and the first
+    /// stack is taken.
+    Lowest,
+    /// A random card in the player's hand is played, and a random stack
+    /// is taken.
+    Random,
+}
+
+/// A decision a game asks a player for.
+#[derive(Clone, Debug)]
+pub(crate) enum Decision {
+    Play,
+    /// Choose a stack to take for the given card.
+    Choose(Card),
+}
+
+/// A player's answer to a `Decision`.
+pub(crate) enum Answer {
+    Play(Result<Card, PlayerError>),
+    Choose(Result<StackId, PlayerError>),
+}
+
+/// A player who took too long to decide, and their late answer.
+type Returned = (Box<dyn Player>, Answer);
+
+/// The time a seat's player has used, and the player itself while it's
+/// still deciding after running out of time.
+#[derive(Default)]
+pub(crate) struct Clock {
+    pub(crate) used: Duration,
+    away: Option<(Receiver<Returned>, Receiver<Missed>)>,
+}
+
+impl Clock {
+    /// Asks the player in the given seat for a decision with the given
+    /// view, within the time left on this clock. Returns `None` when the
+    /// player runs out of time, leaving an `Away` player in the seat until
+    /// it answers.
+    pub(crate) fn decide(
+        &mut self,
+        seat: &mut Box<dyn Player>,
+        limit: &TimeLimit,
+        decision: Decision,
+        view: PlayerView,
+    ) -> Option<Answer> {
+        if !limit.is_limited() {
+            return Some(ask(&mut **seat, decision, &view));
+        }
+        self.reclaim(seat);
+        if self.away.is_some() {
+            return None;
+        }
+        let left = limit
+            .game
+            .map(|ms| Duration::from_millis(ms).saturating_sub(self.used));
+        let budget = match (limit.decision.map(Duration::from_millis), left) {
+            (Some(a), Some(b)) => cmp::min(a, b),
+            (a, b) => a.or(b).unwrap_or_default(),
+        };
+        if budget == Duration::from_millis(0) {
+            return None;
+        }
+
+        let (misser, missed) = channel();
+        let away = Box::new(Away::new(&**seat, misser));
+        let mut player = mem::replace(seat, away);
+        let (sender, receiver) = channel();
+        let start = Instant::now();
+        thread::spawn(move || {
+            let answer = ask(&mut *player, decision, &view);
+            let _ = sender.send((player, answer));
+        });
+        match receiver.recv_timeout(budget) {
+            Ok((player, answer)) => {
+                self.used += start.elapsed();
+                *seat = player;
+                Some(answer)
+            }
+            Err(_) => {
+                self.used += budget;
+                self.away = Some((receiver, missed));
+                None
+            }
+        }
+    }
+
+    /// Puts a player who has answered late back in their seat, catching
+    /// them up on everything they missed.
+    fn reclaim(&mut self, seat: &mut Box<dyn Player>) {
+        let (mut player, answer) = match self.away {
+            Some((ref returned, _)) => match returned.try_recv() {
+                Ok(returned) => returned,
+                Err(_) => return,
+            },
+            None => return,
+        };
+        // The late card is still the player's, until they play it again.
+        if let Answer::Play(Ok(card)) = answer {
+            player.draw(card);
+        }
+        if let Some((_, missed)) = self.away.take() {
+            for missed in missed.try_iter() {
+                missed.replay(&mut *player);
+            }
+        }
+        *seat = player;
+    }
+}
+
+fn ask(player: &mut dyn Player, decision: Decision, view: &PlayerView) -> Answer {
+    match decision {
+        Decision::Play => Answer::Play(player.play(view)),
+        Decision::Choose(ref card) => Answer::Choose(player.choose(card, view.board(), view)),
+    }
+}
+
+/// Something a player missed while away.
+enum Missed {
+    Deck(Vec<Card>),
+    Draw(Card),
+    Discard(Card),
+    Board(Board),
+    Played(Vec<(Card, Id)>),
+    Taken(Vec<Card>, Id),
+    Take(Vec<Card>),
+    RoundStarted(usize),
+    RoundEnded(usize, Vec<(Id, u32)>),
+    GameEnded(Box<GameResult>),
+}
+
+impl Missed {
+    fn replay(self, player: &mut dyn Player) {
+        match self {
+            Missed::Deck(deck) => player.look_at_deck(&deck),
+            Missed::Draw(card) => player.draw(card),
+            Missed::Discard(card) => player.discard(&card),
+            Missed::Board(board) => player.look_at_board(&board),
+            Missed::Played(played) => player.look_at_played(&played),
+            Missed::Taken(cards, id) => player.look_at_taken((&cards, id)),
+            Missed::Take(cards) => player.take(cards),
+            Missed::RoundStarted(round) => player.start_round(round),
+            Missed::RoundEnded(round, scores) => player.end_round(round, &scores),
+            Missed::GameEnded(result) => player.end_game(&result),
+        }
+    }
+}
+
+/// Sits in for a player who ran out of time, while they're still deciding
+/// on another thread. Everything the player misses is sent to their
+/// clock, to be replayed to them once they're back.
+struct Away {
+    id: Id,
+    name: String,
+    hand: Vec<Card>,
+    pile: Vec<Card>,
+    missed: Sender<Missed>,
+}
+
+impl Away {
+    fn new(player: &dyn Player, missed: Sender<Missed>) -> Self {
+        Away {
+            id: player.id(),
+            name: player.to_string(),
+            hand: player.hand().to_vec(),
+            pile: player.pile().to_vec(),
+            missed,
+        }
+    }
+
+    fn miss(&self, missed: Missed) {
+        let _ = self.missed.send(missed);
+    }
+}
+
+impl Player for Away {
+    fn id(&self) -> Id {
+        self.id
+    }
+
+    fn hand(&self) -> &[Card] {
+        &self.hand
+    }
+
+    fn pile(&self) -> &[Card] {
+        &self.pile
+    }
+
+    fn draw(&mut self, card: Card) {
+        self.hand.push(card.clone());
+        self.miss(Missed::Draw(card));
+    }
+
+    fn discard(&mut self, card: &Card) {
+        self.hand.retain(|c| c != card);
+        self.miss(Missed::Discard(card.clone()));
+    }
+
+    fn start_round(&mut self, round: usize) {
+        self.miss(Missed::RoundStarted(round));
+    }
+
+    fn end_round(&mut self, round: usize, scores: &[(Id, u32)]) {
+        self.miss(Missed::RoundEnded(round, scores.to_vec()));
+    }
+
+    fn end_game(&mut self, result: &GameResult) {
+        self.miss(Missed::GameEnded(Box::new(result.clone())));
+    }
+
+    fn look_at_deck(&mut self, deck: &[Card]) {
+        self.miss(Missed::Deck(deck.to_vec()));
+    }
+
+    fn look_at_board(&mut self, board: &Board) {
+        self.miss(Missed::Board(board.clone()));
+    }
+
+    fn look_at_played(&mut self, played: &[(Card, Id)]) {
+        self.miss(Missed::Played(played.to_vec()));
+    }
+
+    fn look_at_taken(&mut self, taken: (&[Card], Id)) {
+        self.miss(Missed::Taken(taken.0.to_vec(), taken.1));
+    }
+
+    fn play(&mut self, _view: &PlayerView) -> Result<Card, PlayerError> {
+        Err(PlayerError::new("player is out of time."))
+    }
+
+    fn choose(
+        &self,
+        _card: &Card,
+        _board: &Board,
+        _view: &PlayerView,
+    ) -> Result<StackId, PlayerError> {
+        Err(PlayerError::new("player is out of time."))
+    }
+
+    fn take(&mut self, cards: Vec<Card>) {
+        self.pile.extend(cards.iter().cloned());
+        self.miss(Missed::Take(cards));
+    }
+}
+
+impl fmt::Display for Away {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "{}", self.name)
+    }
+}
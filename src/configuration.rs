@@ -1,80 +1,365 @@
+use crate::placement::Placement;
+use crate::{Penalty, TimeLimit};
 use std::fs::File;
-use std::io::Read;
+use std::io::{self, Read};
 use std::path::Path;
+use std::{error, fmt};
 
-static mut CONFIG: Configuration = Configuration {
-    turn_count: 10,
-    stack_size: 5,
-    win_score: 66,
-    deck_size: 104,
-    bull_range: (1, 7),
-};
-
-/// Global configuration for the game.
-#[derive(Debug, Serialize, Deserialize)]
+/// Configuration for a single game.
+///
+/// Each `Game` owns its own configuration, and hands it to the dealer and
+/// the rest of the structures which need it. This allows any number of
+/// games, each with their own rules, to be played at once.
+///
+/// # Examples
+///
+/// ```
+/// use take5::Configuration;
+///
+/// let config = Configuration {
+///     turn_count: 9,
+///     stack_size: 6,
+///     ..Configuration::default()
+/// };
+/// assert_eq!(config.win_score, Configuration::win_score());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Configuration {
-    turn_count: usize,
-    stack_size: usize,
-    win_score: u32,
-    deck_size: u8,
-    bull_range: (u8, u8),
+    /// The number of turns per round for the game.
+    pub turn_count: usize,
+    /// The size of the stacks on the board.
+    pub stack_size: usize,
+    /// The number of stacks on the board. This field may be left out of
+    /// configuration files, and defaults to 4.
+    #[serde(default = "Configuration::stack_count")]
+    pub stack_count: usize,
+    /// The score at which the game ends after a round, unless there's a
+    /// tie.
+    pub win_score: u32,
+    /// The number of cards to put in the decks.
+    pub deck_size: u16,
+    /// The allowable range of bull values for cards in the form
+    /// `(lower, upper)`, where the values are inclusive.
+    pub bull_range: (u8, u8),
+    /// The rule deciding which stack played cards go on. This field may be
+    /// left out of configuration files, and defaults to `"standard"`.
+    #[serde(default)]
+    pub placement: Placement,
+    /// Play the tactical variant, where the deck only holds the cards which
+    /// are dealt. This field may be left out of configuration files, and
+    /// defaults to `false`.
+    #[serde(default)]
+    pub tactical: bool,
+    /// What happens to a player who breaks the rules. This field may be
+    /// left out of configuration files, and defaults to `"substitute"`.
+    #[serde(default)]
+    pub penalty: Penalty,
+    /// How long players may take to decide. This field may be left out of
+    /// configuration files, and defaults to no limits.
+    #[serde(default)]
+    pub time_limit: TimeLimit,
 }
 
 impl Configuration {
-    /// Loads a configuration from a file and sets the global `CONFIG` to it.
+    /// Reads a configuration from the given path, and validates it for a
+    /// game with `players` players.
     ///
-    /// This the default configuration as a file. All fields are required.
+    /// This the default configuration as a file. All fields are required,
+    /// unless noted otherwise.
     ///
     /// ```json
     /// {
     ///     "turn_count" : 10,
     ///     "stack_size" : 5,
+    ///     "stack_count" : 4,
     ///     "win_score" : 66,
     ///     "deck_size" : 104,
-    ///     "bull_range" : [1, 7]
+    ///     "bull_range" : [1, 7],
+    ///     "placement" : "standard",
+    ///     "tactical" : false,
+    ///     "penalty" : "substitute",
+    ///     "time_limit" : { "decision" : null, "game" : null, "fallback" : "ai" }
     /// }
     /// ```
     ///
-    /// # Unsafety
+    /// # Errors
     ///
-    /// This function should be called before creating any other structures
-    /// from within this crate. This function mutates a static variable
-    /// so calls to this after the values of the config have been used may
-    /// lead to inconsistent results.
-    pub unsafe fn load<R>(filename: R)
+    /// Returns `Err` if the file cannot be read, isn't a valid
+    /// configuration file, or fails `Configuration::validate`.
+    pub fn from_path<P>(path: P, players: usize) -> Result<Configuration, ConfigError>
+    where
+        P: AsRef<Path>,
+    {
+        Configuration::from_reader(File::open(path)?, players)
+    }
+
+    /// Loads a configuration from a file.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the file cannot be read, or doesn't contain
+    /// a valid configuration for at least a single player.
+    #[deprecated(
+        since = "0.2.0",
+        note = "use `Configuration::from_path`, which returns an error for the number of players"
+    )]
+    pub fn load<R>(filename: R) -> Configuration
     where
         R: AsRef<Path>,
     {
-        let mut file = File::open(filename).unwrap();
-        let mut config_string = String::new();
-        file.read_to_string(&mut config_string).unwrap();
-        CONFIG = serde_json::from_str(&config_string).unwrap();
+        Configuration::from_path(filename, 1).unwrap_or_else(|e| panic!("{}", e))
     }
 
-    /// Returns the number of turns per round for the game.
+    /// Reads a configuration from the given reader, and validates it for
+    /// a game with `players` players. See `Configuration::from_path` for
+    /// the format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use take5::{ConfigError, Configuration};
+    ///
+    /// let json = r#"{
+    ///     "turn_count" : 9,
+    ///     "stack_size" : 6,
+    ///     "win_score" : 66,
+    ///     "deck_size" : 210,
+    ///     "bull_range" : [3, 7]
+    /// }"#;
+    /// let config = Configuration::from_reader(json.as_bytes(), 10).unwrap();
+    /// assert_eq!(config.deck_size, 210);
+    ///
+    /// let json = r#"{
+    ///     "turn_count" : 10,
+    ///     "stack_size" : 5,
+    ///     "win_score" : 66,
+    ///     "deck_size" : 104,
+    ///     "bull_range" : [7, 1]
+    /// }"#;
+    /// match Configuration::from_reader(json.as_bytes(), 4) {
+    ///     Err(ConfigError::Invalid { field, .. }) => assert_eq!(field, "bull_range"),
+    ///     _ => panic!("expected an invalid bull range"),
+    /// }
+    /// ```
+    pub fn from_reader<R>(reader: R, players: usize) -> Result<Configuration, ConfigError>
+    where
+        R: Read,
+    {
+        let config: Configuration = serde_json::from_reader(reader)?;
+        config.validate(players)?;
+        Ok(config)
+    }
+
+    /// Checks that a game with `players` players can be played with this
+    /// configuration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use take5::Configuration;
+    ///
+    /// let config = Configuration::default();
+    /// assert!(config.validate(10).is_ok());
+    /// assert!(config.validate(0).is_err());
+    /// let err = config.validate(11).unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "invalid deck_size: 104 cards cannot deal 10 cards to 11 players \
+    ///      and flip 4 stacks, at least 114 are needed",
+    /// );
+    /// ```
+    pub fn validate(&self, players: usize) -> Result<(), ConfigError> {
+        if players == 0 {
+            return Err(ConfigError::invalid("players", "must be at least 1"));
+        }
+        if self.turn_count == 0 {
+            return Err(ConfigError::invalid("turn_count", "must be at least 1"));
+        }
+        if self.stack_size == 0 {
+            return Err(ConfigError::invalid("stack_size", "must be at least 1"));
+        }
+        if self.stack_count == 0 {
+            return Err(ConfigError::invalid("stack_count", "must be at least 1"));
+        }
+        if self.win_score == 0 {
+            return Err(ConfigError::invalid("win_score", "must be at least 1"));
+        }
+        let (lower, upper) = self.bull_range;
+        if lower > upper {
+            return Err(ConfigError::invalid(
+                "bull_range",
+                format!(
+                    "lower bound {} is greater than upper bound {}",
+                    lower, upper
+                ),
+            ));
+        }
+        let stacks = self.stack_count;
+        let needed = players
+            .saturating_mul(self.turn_count)
+            .saturating_add(stacks);
+        if (self.deck_size as usize) < needed {
+            return Err(ConfigError::invalid(
+                "deck_size",
+                format!(
+                    "{} cards cannot deal {} cards to {} players and flip {} stacks, \
+                     at least {} are needed",
+                    self.deck_size, self.turn_count, players, stacks, needed
+                ),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the configuration a dealer should open a deck with, for a
+    /// round with `players` players.
+    ///
+    /// This is the same configuration, except in the tactical variant.
+    /// There only the cards `1..=(players * turn_count + stack_count)` are
+    /// used, so every card in the deck is dealt to a player or flipped onto
+    /// the board.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` in the tactical variant when the deck would hold more
+    /// cards than a face value can count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use take5::Configuration;
+    ///
+    /// let config = Configuration::default();
+    /// assert_eq!(config.for_players(3).unwrap().deck_size, 104);
+    ///
+    /// let config = Configuration { tactical: true, ..Configuration::default() };
+    /// assert_eq!(config.for_players(3).unwrap().deck_size, 34);
+    /// assert!(config.for_players(10_000).is_err());
+    /// ```
+    pub fn for_players(&self, players: usize) -> Result<Configuration, ConfigError> {
+        let mut config = self.clone();
+        if self.tactical {
+            let needed = players
+                .checked_mul(self.turn_count)
+                .and_then(|dealt| dealt.checked_add(self.stack_count));
+            config.deck_size = needed
+                .and_then(|needed| u16::try_from(needed).ok())
+                .ok_or_else(|| {
+                    ConfigError::invalid(
+                        "deck_size",
+                        format!(
+                            "a tactical deck for {} players has more than {} cards",
+                            players,
+                            u16::MAX
+                        ),
+                    )
+                })?;
+        }
+        Ok(config)
+    }
+
+    /// Returns the default number of turns per round for the game.
     pub fn turn_count() -> usize {
-        unsafe { CONFIG.turn_count }
+        Configuration::default().turn_count
     }
 
-    /// Returns the size of the stacks on the board.
+    /// Returns the default size of the stacks on the board.
     pub fn stack_size() -> usize {
-        unsafe { CONFIG.stack_size }
+        Configuration::default().stack_size
+    }
+
+    /// Returns the default number of stacks on the board.
+    pub fn stack_count() -> usize {
+        Configuration::default().stack_count
     }
 
-    /// Returns the score at which the game ends after a round, unless
-    /// there's a tie.
+    /// Returns the default score at which the game ends after a round,
+    /// unless there's a tie.
     pub fn win_score() -> u32 {
-        unsafe { CONFIG.win_score }
+        Configuration::default().win_score
     }
 
-    /// Returns the number of cards to put in the decks.
-    pub fn deck_size() -> u8 {
-        unsafe { CONFIG.deck_size }
+    /// Returns the default number of cards to put in the decks.
+    pub fn deck_size() -> u16 {
+        Configuration::default().deck_size
     }
 
-    /// Returns the allowable range of bull values for cards in the form
-    /// `(lower, upper)`, where the values are inclusive.
+    /// Returns the default allowable range of bull values for cards in the
+    /// form `(lower, upper)`, where the values are inclusive.
     pub fn bull_range() -> (u8, u8) {
-        unsafe { CONFIG.bull_range }
+        Configuration::default().bull_range
+    }
+}
+
+impl Default for Configuration {
+    fn default() -> Self {
+        Configuration {
+            turn_count: 10,
+            stack_size: 5,
+            stack_count: 4,
+            win_score: 66,
+            deck_size: 104,
+            bull_range: (1, 7),
+            placement: Placement::Standard,
+            tactical: false,
+            penalty: Penalty::Substitute,
+            time_limit: TimeLimit::default(),
+        }
+    }
+}
+
+/// An error loading or validating a `Configuration`.
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The configuration couldn't be read.
+    Io(io::Error),
+    /// The configuration isn't valid JSON, or is missing fields.
+    Parse(serde_json::Error),
+    /// The configuration parsed, but the given `field`, or the number of
+    /// `players` it's validated for, has an impossible value for the
+    /// `reason` given.
+    Invalid { field: &'static str, reason: String },
+}
+
+impl ConfigError {
+    fn invalid<S: Into<String>>(field: &'static str, reason: S) -> Self {
+        ConfigError::Invalid {
+            field,
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            ConfigError::Io(ref e) => write!(f, "could not read configuration: {}", e),
+            ConfigError::Parse(ref e) => write!(f, "could not parse configuration: {}", e),
+            ConfigError::Invalid { field, ref reason } => {
+                write!(f, "invalid {}: {}", field, reason)
+            }
+        }
+    }
+}
+
+impl error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            ConfigError::Io(ref e) => Some(e),
+            ConfigError::Parse(ref e) => Some(e),
+            ConfigError::Invalid { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for ConfigError {
+    fn from(e: io::Error) -> Self {
+        ConfigError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for ConfigError {
+    fn from(e: serde_json::Error) -> Self {
+        ConfigError::Parse(e)
     }
 }
@@ -1,48 +1,104 @@
-use crate::player::Id;
-use crate::{Board, Card, Configuration, Player};
+use crate::observer::GameObserver;
+use crate::placement::PlacementRule;
+use crate::player::{Id, PlayerView};
+use crate::{resolve_turn, Board, Card, Configuration, Error, Outcome, Player, StackId};
+use rand::{Rng, SeedableRng, StdRng};
 
+pub use self::replay_dealer::ReplayDealer;
 pub use self::standard_dealer::StandardDealer;
 
 /// A dealer facilitates the game dealing and playing.
 pub trait Dealer {
-    /// Open a new deck of cards and shuffle it.
+    /// Open a new deck of cards for the given configuration and shuffle it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the dealer can't make a deck for the given
+    /// configuration, such as when its bull values aren't in the
+    /// configuration's `bull_range`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use take5::{Configuration, Error};
+    /// use take5::dealer::{Dealer, StandardDealer};
+    ///
+    /// let mut dealer = StandardDealer::default();
+    /// assert!(dealer.open(&Configuration::default()).is_ok());
+    ///
+    /// // The standard deck has cards with a single bull.
+    /// let config = Configuration { bull_range: (2, 7), ..Configuration::default() };
+    /// match dealer.open(&config) {
+    ///     Err(Error::BullRange { bull, .. }) => assert_eq!(bull, 1),
+    ///     _ => panic!("expected a bull out of range"),
+    /// }
+    /// ```
+    fn open(&mut self, config: &Configuration) -> Result<(), Error>;
+
+    /// Returns the cards left in the deck. Right after opening a deck this
+    /// is every card in play for the round.
+    ///
+    /// By default this is empty, for dealers which keep their deck hidden.
+    /// Players are then shown an empty deck before each round, and
+    /// snapshots of the game hold no deck to resume.
     ///
     /// # Examples
     ///
     /// ```
+    /// use take5::Configuration;
     /// use take5::dealer::{Dealer, StandardDealer};
     ///
+    /// let config = Configuration { tactical: true, ..Configuration::default() };
     /// let mut dealer = StandardDealer::default();
-    /// dealer.open();
+    /// dealer.open(&config.for_players(2).unwrap()).unwrap();
+    /// assert_eq!(dealer.deck().len(), 24);
+    /// dealer.deal().unwrap();
+    /// assert_eq!(dealer.deck().len(), 23);
     /// ```
-    fn open(&mut self);
+    fn deck(&self) -> &[Card] {
+        &[]
+    }
+
+    /// Replace the deck with the given cards, as returned by `deck`. This
+    /// is used to resume a game from a `GameSnapshot`. By default the
+    /// cards are ignored, as decks are only dealt from at the start of a
+    /// round.
+    fn restore(&mut self, _deck: Vec<Card>) {}
+
+    /// Returns the seed this dealer shuffles with, if it has one. Dealing
+    /// again from the same seed deals the same cards.
+    fn seed(&self) -> Option<u64> {
+        None
+    }
 
     /// Return the next card off of the deck.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// This function can panic if the dealer runs out of cards in the deck,
-    /// or if the dealer never opened a deck of cards to start with.
+    /// Returns `Error::EmptyDeck` if the dealer runs out of cards in the
+    /// deck, or if the dealer never opened a deck of cards to start with.
     ///
     /// # Examples
     ///
     /// ```
-    /// use take5::Configuration;
+    /// use take5::{Configuration, Error};
     /// use take5::dealer::{Dealer, StandardDealer};
     ///
+    /// let config = Configuration::default();
     /// let mut dealer = StandardDealer::default();
-    /// dealer.open();
-    /// let card = dealer.deal();
-    /// assert!(1 <= card.face() && card.face() <= Configuration::deck_size());
+    /// assert_eq!(dealer.deal(), Err(Error::EmptyDeck));
+    /// dealer.open(&config).unwrap();
+    /// let card = dealer.deal().unwrap();
+    /// assert!(1 <= card.face() && card.face() <= config.deck_size);
     /// ```
-    fn deal(&mut self) -> Card;
+    fn deal(&mut self) -> Result<Card, Error>;
 
-    /// Deal out `Configuration::turn_count()` cards to all given players.
+    /// Deal out `config.turn_count` cards to all given players.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// This function can panic if the dealer runs out of cards in the deck,
-    /// or if the dealer never opened a deck of cards to start with.
+    /// Returns `Error::EmptyDeck` if the dealer runs out of cards in the
+    /// deck, or if the dealer never opened a deck of cards to start with.
     ///
     /// # Examples
     ///
@@ -51,135 +107,302 @@ pub trait Dealer {
     /// use take5::dealer::{Dealer, StandardDealer};
     /// use take5::player::{Player, AiPlayer};
     ///
+    /// let config = Configuration::default();
     /// let mut dealer = StandardDealer::default();
     /// let mut players: Vec<Box<dyn Player>> = (0..2).map(|id| {
     ///     Box::new(AiPlayer::new(id)) as Box<dyn Player>
     /// }).collect();
-    /// dealer.open();
-    /// dealer.deals(&mut players);
+    /// dealer.open(&config).unwrap();
+    /// dealer.deals(&mut players, &config).unwrap();
     /// for player in players.iter() {
-    ///     assert_eq!(player.hand().len(), Configuration::turn_count());
+    ///     assert_eq!(player.hand().len(), config.turn_count);
     /// }
     /// ```
-    fn deals(&mut self, players: &mut [Box<dyn Player>]) {
-        for _ in 0..Configuration::turn_count() {
+    fn deals(
+        &mut self,
+        players: &mut [Box<dyn Player>],
+        config: &Configuration,
+    ) -> Result<(), Error> {
+        for _ in 0..config.turn_count {
             for player in players.iter_mut() {
-                player.draw(self.deal());
+                player.draw(self.deal()?);
             }
         }
+        Ok(())
     }
 
-    /// Clear and deal a card to each stack on the given board.
+    /// Reset the given board to `config.stack_count` empty stacks, and deal
+    /// a card to each of them.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// This function can panic if the dealer runs out of cards in the deck,
-    /// or if the dealer never opened a deck of cards to start with.
+    /// Returns `Error::EmptyDeck` if the dealer runs out of cards in the
+    /// deck, or if the dealer never opened a deck of cards to start with.
     ///
     /// # Examples
     ///
     /// ```
-    /// use take5::Board;
+    /// use take5::{Board, Configuration};
     /// use take5::dealer::{Dealer, StandardDealer};
     ///
+    /// let config = Configuration::default();
     /// let mut board = Board::default();
     /// let mut dealer = StandardDealer::default();
-    /// dealer.open();
-    /// dealer.flip(&mut board);
+    /// dealer.open(&config).unwrap();
+    /// dealer.flip(&mut board, &config).unwrap();
+    /// assert_eq!(board.len(), config.stack_count);
     /// ```
-    fn flip(&mut self, board: &mut Board) {
-        board.clear();
+    fn flip(&mut self, board: &mut Board, config: &Configuration) -> Result<(), Error> {
+        *board = Board::new(config.stack_count);
         for stack in board {
-            let card = self.deal();
-            stack.push(card);
+            let card = self.deal()?;
+            stack.try_push(card, config)?;
         }
+        Ok(())
+    }
+
+    /// Returns the placement rule used to stack cards. By default this is
+    /// the configuration's `placement`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use take5::{Board, Card, Configuration, StackId};
+    /// use take5::dealer::{seeded_rng, Dealer, StandardDealer};
+    /// use take5::observer::GameEvent;
+    /// use take5::placement::Placement;
+    /// use take5::player::{AiPlayer, Player};
+    ///
+    /// let config = Configuration {
+    ///     placement: Placement::Professional,
+    ///     ..Configuration::default()
+    /// };
+    /// let deck = Card::deck(&config, |_| 1);
+    /// let mut dealer = StandardDealer::default();
+    /// let mut players = vec![Box::new(AiPlayer::new(0)) as Box<dyn Player>];
+    ///
+    /// // A full stack of 11 through 15.
+    /// let mut board = Board::new(1);
+    /// for card in &deck[10..15] {
+    ///     board[StackId::A].push(card.clone(), &config);
+    /// }
+    ///
+    /// // Placing 5 underneath the stack is the sixth card, so it's taken.
+    /// let played = vec![(deck[4].clone(), 0)];
+    /// let mut rng = seeded_rng(1);
+    /// dealer.stack(&mut board, played, &mut players, &config, &mut rng, &mut |_: &GameEvent| {}).unwrap();
+    /// assert_eq!(players[0].pile().len(), 5);
+    /// assert_eq!(board[StackId::A].len(), 1);
+    /// ```
+    fn placement<'a>(&'a self, config: &'a Configuration) -> &'a dyn PlacementRule {
+        &config.placement
     }
 
     /// Stack the given played cards on the given board.
     ///
     /// Stacking cards is done in order of least face valued card to most
-    /// valued. Cards are placed on to the stack with the largest face value
-    /// which is smaller than the placed card. If this stack is full
-    /// (has 5 cards) then the player takes that stack before placing the card
+    /// valued. Cards are placed on to the stack given by the dealer's
+    /// `placement` rule, which by default is the stack with the largest face
+    /// value which is smaller than the placed card. If this stack is full
+    /// (has `config.stack_size` cards) then the player takes that stack before placing the card
     /// onto the stack. If the played card's face value is smaller than all of
     /// the stacks then the player must *choose* the stack he/she wishes to
     /// take before placing the card into that stack.
     ///
-    /// Whenever a player takes a stack, all players look at the taken stack.
+    /// The turn is resolved with `resolve_turn`, which asks the players to
+    /// choose as it goes. A player who fails to choose, or chooses a stack
+    /// which isn't on the board, takes a stack drawn from `rng`, so seeding
+    /// it makes stacking reproducible, see `seeded_rng`. The played cards
+    /// aren't checked, as they are by a `Game`. Then, for every stack taken, all players look at
+    /// the taken stack. Every card placed and stack taken is reported to
+    /// the `observer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if a card was played by a player who isn't given, or
+    /// `resolve_turn` fails. The board and players are left as they were.
     ///
     /// # Examples
     ///
     /// ```
-    /// use take5::Board;
-    /// use take5::dealer::{Dealer, StandardDealer};
+    /// use take5::{Board, Configuration};
+    /// use take5::dealer::{seeded_rng, Dealer, StandardDealer};
+    /// use take5::observer::GameEvent;
     /// use take5::player::{Player, AiPlayer};
     ///
+    /// let config = Configuration::default();
     /// let mut board = Board::default();
     /// let mut dealer = StandardDealer::default();
     /// let mut players: Vec<Box<dyn Player>> = (0..2).map(|id| {
     ///     Box::new(AiPlayer::new(id)) as Box<dyn Player>
     /// }).collect();
-    /// dealer.open();
-    /// dealer.flip(&mut board);
-    /// let played = (0..2).map(|id| (dealer.deal(), id)).collect();
-    /// dealer.stack(&mut board, played, &mut players);
+    /// dealer.open(&config).unwrap();
+    /// dealer.flip(&mut board, &config).unwrap();
+    /// let played = (0..2).map(|id| (dealer.deal().unwrap(), id)).collect();
+    /// let mut placed = 0;
+    /// let mut rng = seeded_rng(1);
+    /// dealer.stack(&mut board, played, &mut players, &config, &mut rng, &mut |event: &GameEvent| {
+    ///     if let GameEvent::CardPlaced { .. } = *event {
+    ///         placed += 1;
+    ///     }
+    /// }).unwrap();
+    /// assert_eq!(placed, 2);
     /// ```
     fn stack(
         &mut self,
         board: &mut Board,
-        mut played: Vec<(Card, Id)>,
+        played: Vec<(Card, Id)>,
         players: &mut [Box<dyn Player>],
-    ) {
-        played.sort_by(|a, b| a.0.cmp(&b.0));
-
-        for (card, id) in played.into_iter() {
-            // Find the stack `card` should be put on, if it exists.
-            let stack_id = board.closest_smaller(&card);
-
-            match stack_id {
-                // The player's card can be put on a stack.
-                Some(i) => {
-                    // If the stack is full, give the stack to the player.
-                    if board[i].full() {
-                        let cards = board[i].give();
-                        for player in players.iter_mut() {
-                            player.look_at_taken((&cards[..], id));
+        config: &Configuration,
+        mut rng: &mut dyn Rng,
+        observer: &mut dyn GameObserver,
+    ) -> Result<(), Error> {
+        seated(&played, players)?;
+        let (after, outcomes) = resolve_turn(
+            board,
+            &played,
+            self.placement(config),
+            config,
+            |board, card, id| {
+                if let Some(player) = players.iter_mut().find(|p| p.id() == id) {
+                    player.look_at_board(board);
+                    let view = PlayerView::new(&**player, board, config);
+                    if let Ok(stack) = player.choose(card, board, &view) {
+                        if board.get(stack).is_some() {
+                            return stack;
                         }
-                        players
-                            .iter_mut()
-                            .find(|p| p.id() == id)
-                            .expect("player not found.")
-                            .take(cards);
                     }
-
-                    board[i].push(card);
                 }
-                // The player's card is smaller than each stack, and therefor
-                // the player must choose a stack to take.
-                None => {
-                    let choice = {
-                        let player = players
-                            .iter_mut()
-                            .find(|p| p.id() == id)
-                            .expect("player not found.");
-                        player.look_at_board(&board);
-                        player.choose()
-                    };
-                    let cards = board[choice].give();
-                    for player in players.iter_mut() {
-                        player.look_at_taken((&cards[..], id));
-                    }
-                    players
-                        .iter_mut()
-                        .find(|p| p.id() == id)
-                        .expect("player not found.")
-                        .take(cards);
+                StackId::new((&mut rng).gen_range(0, board.len()))
+            },
+        )?;
+        *board = after;
+        report(outcomes, players, observer);
+        Ok(())
+    }
 
-                    board[choice].push(card);
-                }
+    /// Place a single played card on the given board, as `stack` does for
+    /// each of the played cards. When the card doesn't fit on any stack
+    /// the player takes the stack given by `choice`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::NoChoice` if the card doesn't fit on any stack and
+    /// `choice` is `None`, or fails as `stack` does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use take5::{Board, Card, Configuration, StackId};
+    /// use take5::dealer::{Dealer, StandardDealer};
+    /// use take5::observer::GameEvent;
+    /// use take5::player::{AiPlayer, Player};
+    ///
+    /// let config = Configuration::default();
+    /// let deck = Card::deck(&config, |_| 1);
+    /// let mut dealer = StandardDealer::default();
+    /// let mut players = vec![Box::new(AiPlayer::new(0)) as Box<dyn Player>];
+    /// let mut board = Board::new(2);
+    /// board[StackId::A].push(deck[10].clone(), &config);
+    /// board[StackId::B].push(deck[20].clone(), &config);
+    ///
+    /// // 5 is lower than every stack, so the player takes `B`.
+    /// let played = (deck[4].clone(), 0);
+    /// let mut ignore = |_: &GameEvent| {};
+    /// dealer.place(&mut board, played, Some(StackId::B), &mut players, &config, &mut ignore).unwrap();
+    /// assert_eq!(players[0].pile(), &deck[20..21]);
+    /// assert_eq!(board[StackId::B].last(), Some(&deck[4]));
+    /// ```
+    fn place(
+        &mut self,
+        board: &mut Board,
+        played: (Card, Id),
+        choice: Option<StackId>,
+        players: &mut [Box<dyn Player>],
+        config: &Configuration,
+        observer: &mut dyn GameObserver,
+    ) -> Result<(), Error> {
+        let played = [played];
+        seated(&played, players)?;
+        let mut missing = None;
+        let (after, outcomes) = resolve_turn(
+            board,
+            &played,
+            self.placement(config),
+            config,
+            |_, _, id| {
+                choice.unwrap_or_else(|| {
+                    missing = Some(id);
+                    StackId::A
+                })
+            },
+        )?;
+        if let Some(id) = missing {
+            return Err(Error::NoChoice(id));
+        }
+        *board = after;
+        report(outcomes, players, observer);
+        Ok(())
+    }
+}
+
+/// Checks that every played card was played by one of the given players.
+fn seated(played: &[(Card, Id)], players: &[Box<dyn Player>]) -> Result<(), Error> {
+    for &(_, id) in played {
+        if !players.iter().any(|p| p.id() == id) {
+            return Err(Error::PlayerNotFound(id));
+        }
+    }
+    Ok(())
+}
+
+/// Gives the players and the observer the outcomes of placing cards.
+/// Whenever a player takes a stack all players look at the taken stack.
+fn report(
+    outcomes: Vec<Outcome>,
+    players: &mut [Box<dyn Player>],
+    observer: &mut dyn GameObserver,
+) {
+    for outcome in outcomes {
+        if let Outcome::Taken {
+            player: id,
+            ref cards,
+            ..
+        } = outcome
+        {
+            for player in players.iter_mut() {
+                player.look_at_taken((&cards[..], id));
+            }
+            // Every player who plays is seated, see `seated`.
+            if let Some(player) = players.iter_mut().find(|p| p.id() == id) {
+                player.take(cards.clone());
             }
         }
+        observer.notify(&outcome.into());
     }
 }
 
+/// Returns a random number generator built from the given seed. Dealers
+/// built from a seed shuffle with this generator.
+///
+/// # Examples
+///
+/// ```
+/// extern crate rand;
+/// extern crate take5;
+///
+/// use rand::Rng;
+/// use take5::dealer::seeded_rng;
+///
+/// # fn main() {
+/// let a: u32 = seeded_rng(7).gen();
+/// let b: u32 = seeded_rng(7).gen();
+/// assert_eq!(a, b);
+/// # }
+/// ```
+pub fn seeded_rng(seed: u64) -> StdRng {
+    StdRng::from_seed(&[(seed >> 32) as usize, seed as u32 as usize][..])
+}
+
+mod replay_dealer;
 mod standard_dealer;
@@ -0,0 +1,73 @@
+use crate::observer::GameEvent;
+use crate::{Card, Configuration, Dealer, Error, GameRecord};
+use std::collections::VecDeque;
+
+/// A dealer who deals the hands and boards of a recorded game again.
+///
+/// Each round the dealer opens a deck of exactly the cards dealt in that
+/// round of the record, in the order they were dealt, ignoring the given
+/// configuration. See `GameRecord::replay`.
+#[derive(Debug, Clone)]
+pub struct ReplayDealer {
+    deck: Vec<Card>,
+    rounds: VecDeque<Vec<Card>>,
+    seed: Option<u64>,
+}
+
+impl ReplayDealer {
+    /// Creates a dealer for the rounds of the given record.
+    pub fn new(record: &GameRecord) -> Self {
+        let mut rounds = VecDeque::new();
+        let mut dealt = Vec::new();
+        for event in record.events() {
+            match *event {
+                GameEvent::HandsDealt { ref hands } => {
+                    let turns = hands.iter().map(|h| h.1.len()).max().unwrap_or(0);
+                    for turn in 0..turns {
+                        for (_, hand) in hands {
+                            dealt.extend(hand.get(turn).cloned());
+                        }
+                    }
+                }
+                GameEvent::BoardFlipped { ref board } => {
+                    for stack in board {
+                        dealt.extend(stack.first().cloned());
+                    }
+                    // Cards are dealt off the end of the deck.
+                    dealt.reverse();
+                    rounds.push_back(dealt);
+                    dealt = Vec::new();
+                }
+                _ => {}
+            }
+        }
+        ReplayDealer {
+            deck: Vec::new(),
+            rounds,
+            seed: record.seed(),
+        }
+    }
+}
+
+impl Dealer for ReplayDealer {
+    fn open(&mut self, _config: &Configuration) -> Result<(), Error> {
+        self.deck = self.rounds.pop_front().unwrap_or_default();
+        Ok(())
+    }
+
+    fn deck(&self) -> &[Card] {
+        &self.deck
+    }
+
+    fn restore(&mut self, deck: Vec<Card>) {
+        self.deck = deck;
+    }
+
+    fn deal(&mut self) -> Result<Card, Error> {
+        self.deck.pop().ok_or(Error::EmptyDeck)
+    }
+
+    fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+}
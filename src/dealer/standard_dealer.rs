@@ -1,25 +1,121 @@
-use crate::{Card, Dealer};
+use super::seeded_rng;
+use crate::{Card, Configuration, Dealer, Error};
 use rand::{self, Rng};
+use std::fmt;
 
 /// A dealer who attempts to make the game as fair as possible.
-#[derive(Debug, Default)]
+///
+/// The deck is shuffled with the dealer's own random number generator. A
+/// dealer built from a seed deals the same cards every time, so a game can
+/// be reproduced by playing it again with the same seed and players. The
+/// default dealer picks a random seed.
+///
+/// # Examples
+///
+/// ```
+/// use take5::Game;
+/// use take5::dealer::{Dealer, StandardDealer};
+/// use take5::player::{AiPlayer, Player};
+///
+/// let play = |seed| {
+///     let players = (0..4).map(|id| {
+///         Box::new(AiPlayer::new(id)) as Box<dyn Player>
+///     }).collect();
+///     Game::new(Box::new(StandardDealer::seeded(seed)), players).run()
+/// };
+/// let result = play(5);
+/// assert_eq!(result.seed, Some(5));
+/// assert_eq!(result, play(5));
+///
+/// assert!(StandardDealer::default().seed().is_some());
+/// ```
 pub struct StandardDealer {
     deck: Vec<Card>,
+    rng: Box<dyn Rng + Send>,
+    seed: Option<u64>,
+}
+
+impl StandardDealer {
+    /// Creates a dealer which shuffles with a generator built from the
+    /// given seed.
+    pub fn seeded(seed: u64) -> Self {
+        StandardDealer {
+            deck: Vec::new(),
+            rng: Box::new(seeded_rng(seed)),
+            seed: Some(seed),
+        }
+    }
+
+    /// Creates a dealer which shuffles with the given generator. The
+    /// dealer has no seed to report.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rand;
+    /// extern crate take5;
+    ///
+    /// use rand::{SeedableRng, XorShiftRng};
+    /// use take5::Configuration;
+    /// use take5::dealer::{Dealer, StandardDealer};
+    ///
+    /// # fn main() {
+    /// let rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+    /// let mut dealer = StandardDealer::with_rng(rng);
+    /// dealer.open(&Configuration::default()).unwrap();
+    /// assert_eq!(dealer.seed(), None);
+    /// # }
+    /// ```
+    pub fn with_rng<R: Rng + Send + 'static>(rng: R) -> Self {
+        StandardDealer {
+            deck: Vec::new(),
+            rng: Box::new(rng),
+            seed: None,
+        }
+    }
+}
+
+impl Default for StandardDealer {
+    fn default() -> Self {
+        StandardDealer::seeded(rand::thread_rng().gen())
+    }
+}
+
+impl fmt::Debug for StandardDealer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.debug_struct("StandardDealer")
+            .field("deck", &self.deck)
+            .field("seed", &self.seed)
+            .finish()
+    }
 }
 
 impl Dealer for StandardDealer {
-    fn open(&mut self) {
-        self.deck = Card::deck(|face| match face {
+    fn open(&mut self, config: &Configuration) -> Result<(), Error> {
+        self.deck = Card::try_deck(config, |face| match face {
             55 => 7,
             f if f % 11 == 0 => 5,
             f if f % 10 == 0 => 3,
             f if f % 5 == 0 => 2,
             _ => 1,
-        });
-        rand::thread_rng().shuffle(&mut self.deck)
+        })?;
+        self.rng.shuffle(&mut self.deck);
+        Ok(())
+    }
+
+    fn deck(&self) -> &[Card] {
+        &self.deck
+    }
+
+    fn restore(&mut self, deck: Vec<Card>) {
+        self.deck = deck;
+    }
+
+    fn deal(&mut self) -> Result<Card, Error> {
+        self.deck.pop().ok_or(Error::EmptyDeck)
     }
 
-    fn deal(&mut self) -> Card {
-        self.deck.pop().expect("dealer ran out of cards.")
+    fn seed(&self) -> Option<u64> {
+        self.seed
     }
 }
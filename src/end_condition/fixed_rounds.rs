@@ -0,0 +1,25 @@
+use crate::end_condition::EndCondition;
+use crate::ScoreSheet;
+
+/// The game is over after the given number of rounds.
+///
+/// # Examples
+///
+/// ```
+/// use take5::ScoreSheet;
+/// use take5::end_condition::{EndCondition, FixedRounds};
+///
+/// let mut sheet = ScoreSheet::new(vec![0, 1]);
+/// sheet.record(vec![40, 10]).unwrap();
+/// assert!(!FixedRounds(2).is_over(&sheet));
+/// sheet.record(vec![30, 10]).unwrap();
+/// assert!(FixedRounds(2).is_over(&sheet));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FixedRounds(pub usize);
+
+impl EndCondition for FixedRounds {
+    fn is_over(&self, scores: &ScoreSheet) -> bool {
+        scores.round_count() >= self.0
+    }
+}
@@ -0,0 +1,45 @@
+use crate::ScoreSheet;
+
+pub use self::fixed_rounds::FixedRounds;
+pub use self::rounds_won::RoundsWon;
+pub use self::score_threshold::ScoreThreshold;
+pub use self::unique_winner::UniqueWinner;
+
+/// An end condition decides when a game is over.
+///
+/// The game checks its end condition before every round, and stops once it
+/// returns true. Players disqualified for breaking the rules are left out
+/// of the scores it checks. The game may end with more than one player tied for the
+/// lowest score, unless the condition is wrapped in a `UniqueWinner`.
+///
+/// # Examples
+///
+/// ```
+/// use take5::Game;
+/// use take5::dealer::StandardDealer;
+/// use take5::end_condition::FixedRounds;
+/// use take5::player::{AiPlayer, Player};
+///
+/// let players = (0..4).map(|id| {
+///     Box::new(AiPlayer::new(id)) as Box<dyn Player>
+/// }).collect();
+/// let mut game = Game::new(Box::new(StandardDealer::default()), players);
+/// game.end_condition = Box::new(FixedRounds(3));
+/// game.run();
+/// assert_eq!(game.scores.round_count(), 3);
+/// ```
+pub trait EndCondition {
+    /// Returns true when the game with the given scores is over.
+    fn is_over(&self, scores: &ScoreSheet) -> bool;
+}
+
+impl<C: EndCondition + ?Sized> EndCondition for Box<C> {
+    fn is_over(&self, scores: &ScoreSheet) -> bool {
+        (**self).is_over(scores)
+    }
+}
+
+mod fixed_rounds;
+mod rounds_won;
+mod score_threshold;
+mod unique_winner;
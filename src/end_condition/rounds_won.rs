@@ -0,0 +1,31 @@
+use crate::end_condition::EndCondition;
+use crate::ScoreSheet;
+
+/// The game is over once any player has won the given number of rounds.
+///
+/// A round is won by taking the fewest bulls in it. Every player tied for
+/// the fewest bulls wins the round.
+///
+/// # Examples
+///
+/// ```
+/// use take5::ScoreSheet;
+/// use take5::end_condition::{EndCondition, RoundsWon};
+///
+/// let mut sheet = ScoreSheet::new(vec![0, 1]);
+/// sheet.record(vec![4, 10]).unwrap();
+/// assert!(!RoundsWon(2).is_over(&sheet));
+/// sheet.record(vec![10, 10]).unwrap();
+/// assert!(RoundsWon(2).is_over(&sheet));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RoundsWon(pub usize);
+
+impl EndCondition for RoundsWon {
+    fn is_over(&self, scores: &ScoreSheet) -> bool {
+        scores
+            .players()
+            .iter()
+            .any(|&id| scores.rounds_won(id) >= self.0)
+    }
+}
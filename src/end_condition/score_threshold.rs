@@ -0,0 +1,25 @@
+use crate::end_condition::EndCondition;
+use crate::ScoreSheet;
+
+/// The game is over once any player has at least the given score.
+///
+/// # Examples
+///
+/// ```
+/// use take5::ScoreSheet;
+/// use take5::end_condition::{EndCondition, ScoreThreshold};
+///
+/// let mut sheet = ScoreSheet::new(vec![0, 1]);
+/// sheet.record(vec![40, 10]).unwrap();
+/// assert!(!ScoreThreshold(66).is_over(&sheet));
+/// sheet.record(vec![30, 10]).unwrap();
+/// assert!(ScoreThreshold(66).is_over(&sheet));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ScoreThreshold(pub u32);
+
+impl EndCondition for ScoreThreshold {
+    fn is_over(&self, scores: &ScoreSheet) -> bool {
+        scores.totals().iter().any(|&(_, total)| total >= self.0)
+    }
+}
@@ -0,0 +1,27 @@
+use crate::end_condition::EndCondition;
+use crate::ScoreSheet;
+
+/// The game is over once the wrapped condition is, and exactly one player
+/// has the lowest score. Until the tie is broken more rounds are played.
+///
+/// # Examples
+///
+/// ```
+/// use take5::ScoreSheet;
+/// use take5::end_condition::{EndCondition, ScoreThreshold, UniqueWinner};
+///
+/// let condition = UniqueWinner(ScoreThreshold(66));
+/// let mut sheet = ScoreSheet::new(vec![0, 1, 2]);
+/// sheet.record(vec![70, 10, 10]).unwrap();
+/// assert!(!condition.is_over(&sheet));
+/// sheet.record(vec![0, 0, 3]).unwrap();
+/// assert!(condition.is_over(&sheet));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct UniqueWinner<C>(pub C);
+
+impl<C: EndCondition> EndCondition for UniqueWinner<C> {
+    fn is_over(&self, scores: &ScoreSheet) -> bool {
+        self.0.is_over(scores) && scores.leaders().len() == 1
+    }
+}
@@ -0,0 +1,96 @@
+/// The smallest difference the simplex method tells apart from zero.
+const EPSILON: f64 = 1e-9;
+
+/// Solves the two player zero-sum game with the given costs to the row
+/// player, who wants them as low as possible, while the column player
+/// wants them as high as possible.
+///
+/// Returns the value of the game, which is the cost the row player can
+/// hold the column player to in expectation, and the mixed strategy doing
+/// so, as the probability of each row. The game is solved exactly, as a
+/// linear program, with the simplex method.
+pub(super) fn solve(costs: &[Vec<f64>]) -> (f64, Vec<f64>) {
+    let rows = costs.len();
+    let columns = costs.first().map_or(0, Vec::len);
+    if rows == 0 || columns == 0 {
+        return (0.0, vec![1.0 / rows as f64; rows]);
+    }
+
+    // The row player maximizes the payoffs `shift - cost`, which are at
+    // least 1, so the game's value is positive. The column player's
+    // program is then to maximize `sum(w)` with `payoffs * w <= 1`, and
+    // the row player's strategy is read from its dual.
+    let shift = costs
+        .iter()
+        .flatten()
+        .cloned()
+        .fold(f64::NEG_INFINITY, f64::max)
+        + 1.0;
+    let width = columns + rows + 1;
+    let mut tableau: Vec<Vec<f64>> = costs
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let mut line = vec![0.0; width];
+            for (j, cost) in row.iter().enumerate() {
+                line[j] = shift - cost;
+            }
+            line[columns + i] = 1.0;
+            line[width - 1] = 1.0;
+            line
+        })
+        .collect();
+    let mut objective = vec![0.0; width];
+    for value in objective.iter_mut().take(columns) {
+        *value = -1.0;
+    }
+    let mut basis: Vec<usize> = (columns..columns + rows).collect();
+
+    // Bland's rule, entering the first improving variable, and leaving
+    // the lowest basic variable among ties, never cycles.
+    while let Some(entering) = (0..width - 1).find(|&j| objective[j] < -EPSILON) {
+        let leaving = (0..rows)
+            .filter(|&i| tableau[i][entering] > EPSILON)
+            .min_by(|&a, &b| {
+                let a_ratio = tableau[a][width - 1] / tableau[a][entering];
+                let b_ratio = tableau[b][width - 1] / tableau[b][entering];
+                a_ratio
+                    .partial_cmp(&b_ratio)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+                    .then_with(|| basis[a].cmp(&basis[b]))
+            });
+        // The payoffs are all positive, so the program is bounded.
+        let leaving = match leaving {
+            Some(leaving) => leaving,
+            None => break,
+        };
+        let pivot = tableau[leaving][entering];
+        for value in tableau[leaving].iter_mut() {
+            *value /= pivot;
+        }
+        let line = tableau[leaving].clone();
+        for (i, other) in tableau.iter_mut().enumerate() {
+            let factor = other[entering];
+            if i != leaving && factor != 0.0 {
+                for (value, l) in other.iter_mut().zip(&line) {
+                    *value -= factor * l;
+                }
+            }
+        }
+        let factor = objective[entering];
+        for (value, l) in objective.iter_mut().zip(&line) {
+            *value -= factor * l;
+        }
+        basis[leaving] = entering;
+    }
+
+    let value = 1.0 / objective[width - 1];
+    let mut strategy: Vec<f64> = (0..rows)
+        .map(|i| (objective[columns + i] * value).max(0.0))
+        .collect();
+    let total: f64 = strategy.iter().sum();
+    for p in strategy.iter_mut() {
+        *p /= total;
+    }
+    (shift - value, strategy)
+}
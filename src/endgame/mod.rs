@@ -0,0 +1,309 @@
+use crate::placement::PlacementRule;
+use crate::player::Id;
+use crate::{resolve_turn, Board, Card, Configuration, Error, Outcome, StackId};
+use rand::Rng;
+use std::cmp::Ordering;
+
+mod matrix;
+
+/// An exact solver for the last turns of a round, when every hand is
+/// known.
+///
+/// Every player picks their card at once, so no single card is best to
+/// play; an opponent who knew it would play against it. Instead the
+/// solver finds a mixed strategy, the chance of playing each card, which
+/// keeps the bulls the player expects to take as low as possible, however
+/// their opponents play. Each turn is solved as a zero-sum game between
+/// the player and their opponents together, whose payoffs are the bulls
+/// the player takes over the rest of the turns, solved the same way.
+///
+/// The opponents are assumed to play, and take stacks, to give the player
+/// the most bulls they can. This is exactly right with a single opponent
+/// who wants the player to lose, and otherwise a guarantee: the player
+/// expects to take no more than the solution's bulls.
+///
+/// The work grows very quickly with the number of cards in hand, so the
+/// solver is meant for the last few turns.
+///
+/// # Examples
+///
+/// ```
+/// use take5::{Board, Card, Configuration, StackId};
+/// use take5::endgame::Solver;
+///
+/// let config = Configuration::default();
+/// let deck = Card::deck(&config, |_| 1);
+/// let mut board = Board::new(2);
+/// board[StackId::A].push(deck[25].clone(), &config);
+/// board[StackId::B].push(deck[28].clone(), &config);
+///
+/// // Player 0 holds 9 and 13, and player 1 holds 8 and 12. Every card is
+/// // lower than the board, and whichever card is guessed right by the
+/// // opponent costs a bull, so each is played half of the time.
+/// let hands = vec![
+///     (0, vec![deck[8].clone(), deck[12].clone()]),
+///     (1, vec![deck[7].clone(), deck[11].clone()]),
+/// ];
+/// let solution = Solver::new(0, &config).solve(&board, &hands, 2).unwrap();
+/// assert!((solution.bulls - 0.5).abs() < 1e-9);
+/// for &(_, p) in &solution.strategy {
+///     assert!((p - 0.5).abs() < 1e-9);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Solver<'a> {
+    id: Id,
+    config: &'a Configuration,
+}
+
+/// The solution to the last turns of a round, for one player.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Solution {
+    /// The bulls the player expects to take over the turns solved, at
+    /// most.
+    pub bulls: f64,
+    /// The chance of playing each card in the player's hand this turn.
+    pub strategy: Vec<(Card, f64)>,
+}
+
+impl Solution {
+    /// Returns a card drawn from the strategy, or `None` when the player
+    /// has no cards to play.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use take5::{Card, Configuration};
+    /// use take5::endgame::Solution;
+    ///
+    /// let deck = Card::deck(&Configuration::default(), |_| 1);
+    /// let solution = Solution { bulls: 0.0, strategy: vec![(deck[0].clone(), 1.0)] };
+    /// assert_eq!(solution.sample(&mut rand::thread_rng()), Some(&deck[0]));
+    /// ```
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> Option<&Card> {
+        let mut left = rng.gen::<f64>();
+        for (card, p) in &self.strategy {
+            if left < *p {
+                return Some(card);
+            }
+            left -= p;
+        }
+        // Rounding can leave a little chance over, which goes to the
+        // last card played with any chance.
+        self.strategy
+            .iter()
+            .rev()
+            .find(|&&(_, p)| p > 0.0)
+            .or_else(|| self.strategy.last())
+            .map(|(card, _)| card)
+    }
+}
+
+impl<'a> Solver<'a> {
+    /// Creates a solver for the player with the given id, playing by the
+    /// given configuration.
+    pub fn new(id: Id, config: &'a Configuration) -> Self {
+        Solver { id, config }
+    }
+
+    /// Solves the next `turns` turns from the given board, with every
+    /// player holding the given hands, the player's own included.
+    ///
+    /// Players with an empty hand sit the rest of the turns out, and the
+    /// turns solved stop early when the player runs out of cards. The
+    /// opponents are assumed to play against the player, to give them the
+    /// most bulls they can, see `Solver`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if a card can't be placed on the board, as in
+    /// `resolve_turn`.
+    pub fn solve(
+        &self,
+        board: &Board,
+        hands: &[(Id, Vec<Card>)],
+        turns: usize,
+    ) -> Result<Solution, Error> {
+        let (bulls, strategy) = self.turn(board, hands, turns)?;
+        let hand = self.hand(hands);
+        Ok(Solution {
+            bulls,
+            strategy: hand.iter().cloned().zip(strategy).collect(),
+        })
+    }
+
+    /// Returns the stack the player should take, when their card fits on
+    /// no stack of the given board. The given pending cards are the rest
+    /// of this turn's, which are placed after the player's, and the hands
+    /// are what every player holds for the next `turns` turns.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if a card can't be placed on the board, as in
+    /// `resolve_turn`, or there are no stacks to take.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use take5::{Board, Card, Configuration, StackId};
+    /// use take5::endgame::Solver;
+    ///
+    /// let config = Configuration::default();
+    /// let deck = Card::deck(&config, |_| 1);
+    /// let mut board = Board::new(2);
+    /// board[StackId::A].push(deck[9].clone(), &config);
+    /// board[StackId::B].push(deck[19].clone(), &config);
+    /// for card in &deck[20..23] {
+    ///     board[StackId::B].push(card.clone(), &config);
+    /// }
+    ///
+    /// // Player 0 plays 5, and would rather take the one card of `A`.
+    /// let solver = Solver::new(0, &config);
+    /// let hands = vec![(0, vec![]), (1, vec![])];
+    /// assert_eq!(solver.take(&board, &deck[4], &[], &hands, 0), Ok(StackId::A));
+    /// ```
+    pub fn take(
+        &self,
+        board: &Board,
+        card: &Card,
+        pending: &[(Card, Id)],
+        hands: &[(Id, Vec<Card>)],
+        turns: usize,
+    ) -> Result<StackId, Error> {
+        let mut pending = pending.to_vec();
+        pending.sort();
+        let mut best = None;
+        for stack in board.ids() {
+            let (after, bulls) = self.place(board, &(card.clone(), self.id), Some(stack))?;
+            let value = f64::from(bulls) + self.resolve(&after, &pending, hands, turns)?;
+            if best.is_none_or(|(v, _)| value < v) {
+                best = Some((value, stack));
+            }
+        }
+        best.map(|(_, stack)| stack).ok_or(Error::NoChoice(self.id))
+    }
+
+    /// Returns the player's hand.
+    fn hand<'h>(&self, hands: &'h [(Id, Vec<Card>)]) -> &'h [Card] {
+        hands
+            .iter()
+            .find(|h| h.0 == self.id)
+            .map(|h| &h.1[..])
+            .unwrap_or(&[])
+    }
+
+    /// Solves a turn, returning the bulls the player expects over it and
+    /// the rest, and the chance of playing each card of their hand.
+    fn turn(
+        &self,
+        board: &Board,
+        hands: &[(Id, Vec<Card>)],
+        turns: usize,
+    ) -> Result<(f64, Vec<f64>), Error> {
+        let hand = self.hand(hands);
+        if turns == 0 || hand.is_empty() {
+            return Ok((0.0, vec![1.0 / hand.len() as f64; hand.len()]));
+        }
+
+        // Every combination of cards the opponents could play together.
+        let mut joint: Vec<Vec<(Card, Id)>> = vec![Vec::new()];
+        for &(id, ref cards) in hands {
+            if id != self.id && !cards.is_empty() {
+                joint = joint
+                    .iter()
+                    .flat_map(|played| {
+                        cards.iter().map(move |card| {
+                            let mut played = played.clone();
+                            played.push((card.clone(), id));
+                            played
+                        })
+                    })
+                    .collect();
+            }
+        }
+
+        let mut costs = Vec::with_capacity(hand.len());
+        for card in hand {
+            let mut row = Vec::with_capacity(joint.len());
+            for others in &joint {
+                let mut played = others.clone();
+                played.push((card.clone(), self.id));
+                played.sort();
+                let rest: Vec<(Id, Vec<Card>)> = hands
+                    .iter()
+                    .map(|(id, cards)| {
+                        let left = cards
+                            .iter()
+                            .filter(|c| !played.iter().any(|p| p.0 == **c && p.1 == *id))
+                            .cloned()
+                            .collect();
+                        (*id, left)
+                    })
+                    .collect();
+                row.push(self.resolve(board, &played, &rest, turns - 1)?);
+            }
+            costs.push(row);
+        }
+        Ok(matrix::solve(&costs))
+    }
+
+    /// Returns the bulls the player takes placing the given cards in order,
+    /// and over the turns after.
+    fn resolve(
+        &self,
+        board: &Board,
+        played: &[(Card, Id)],
+        hands: &[(Id, Vec<Card>)],
+        turns: usize,
+    ) -> Result<f64, Error> {
+        let (first, rest) = match played.split_first() {
+            Some(split) => split,
+            None => return Ok(self.turn(board, hands, turns)?.0),
+        };
+        let stacks: Vec<Option<StackId>> = match self.config.placement.place(board, &first.0)? {
+            Some(_) => vec![None],
+            None => board.ids().map(Some).collect(),
+        };
+        let mut values = Vec::with_capacity(stacks.len());
+        for stack in stacks {
+            let (after, bulls) = self.place(board, first, stack)?;
+            values.push(f64::from(bulls) + self.resolve(&after, rest, hands, turns)?);
+        }
+        // The player takes the stack best for them, and opponents the
+        // stack worst for the player.
+        let order = |a: &f64, b: &f64| a.partial_cmp(b).unwrap_or(Ordering::Equal);
+        let value = if first.1 == self.id {
+            values.into_iter().min_by(order)
+        } else {
+            values.into_iter().max_by(order)
+        };
+        value.ok_or(Error::NoChoice(first.1))
+    }
+
+    /// Places a single card, taking the given stack if it fits on none,
+    /// and returns the board after, with the bulls the player took.
+    fn place(
+        &self,
+        board: &Board,
+        played: &(Card, Id),
+        stack: Option<StackId>,
+    ) -> Result<(Board, u32), Error> {
+        let (after, outcomes) = resolve_turn(
+            board,
+            std::slice::from_ref(played),
+            &self.config.placement,
+            self.config,
+            |_, _, _| stack.unwrap_or(StackId::A),
+        )?;
+        let bulls = outcomes
+            .iter()
+            .map(|outcome| match *outcome {
+                Outcome::Taken {
+                    player, ref cards, ..
+                } if player == self.id => cards.iter().map(|c| u32::from(c.bull())).sum(),
+                _ => 0,
+            })
+            .sum();
+        Ok((after, bulls))
+    }
+}
@@ -0,0 +1,104 @@
+use crate::player::Id;
+use crate::{ActionError, ConfigError, StackId};
+use std::{error, fmt};
+
+/// An error playing a game, which would otherwise leave it in an
+/// impossible state.
+///
+/// The dealers, boards and games of this crate return these in place of
+/// panicking, so one broken game can't take down anything else running
+/// alongside it.
+///
+/// # Examples
+///
+/// ```
+/// use take5::{Card, Configuration, Error};
+///
+/// let config = Configuration::default();
+/// assert_eq!(
+///     Card::try_deck(&config, |_| 9),
+///     Err(Error::BullRange { face: 1, bull: 9, range: (1, 7) }),
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A card was put on a stack which already has `stack_size` cards.
+    StackFull,
+    /// The given stack has no cards, though every stack on the board must
+    /// have at least one.
+    EmptyStack(StackId),
+    /// The dealer ran out of cards, or never opened a deck.
+    EmptyDeck,
+    /// A card with the given face would have a bull value outside the
+    /// configured `bull_range`.
+    BullRange {
+        face: u16,
+        bull: u8,
+        range: (u8, u8),
+    },
+    /// No bull value was given for the card with the given face.
+    NoBull(u16),
+    /// There is no player with the given id.
+    PlayerNotFound(Id),
+    /// The player with the given id must choose a stack to take, but no
+    /// choice was given.
+    NoChoice(Id),
+    /// A player's action was rejected by the game.
+    Action(ActionError),
+    /// A round's scores were given for `found` players, on a score sheet
+    /// of `expected` players.
+    ScoreCount { expected: usize, found: usize },
+    /// The game's configuration can't be played, for the given reason,
+    /// see `ConfigError`.
+    Configuration(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            Error::StackFull => write!(f, "attempt to push onto a full stack"),
+            Error::EmptyStack(id) => write!(f, "stack {} doesn't have any cards", id),
+            Error::EmptyDeck => write!(f, "dealer ran out of cards"),
+            Error::BullRange {
+                face,
+                bull,
+                range: (lower, upper),
+            } => write!(
+                f,
+                "bull value of card {} must be in range [{}..{}] was {}",
+                face, lower, upper, bull
+            ),
+            Error::NoBull(face) => write!(f, "no bull value for card {}", face),
+            Error::PlayerNotFound(id) => write!(f, "player {} not found", id),
+            Error::NoChoice(id) => write!(f, "player {} must choose a stack to take", id),
+            Error::Action(ref e) => e.fmt(f),
+            Error::ScoreCount { expected, found } => write!(
+                f,
+                "a round needs a score for each of {} players, got {}",
+                expected, found
+            ),
+            Error::Configuration(ref reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            Error::Action(ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ActionError> for Error {
+    fn from(e: ActionError) -> Self {
+        Error::Action(e)
+    }
+}
+
+impl From<ConfigError> for Error {
+    fn from(e: ConfigError) -> Self {
+        Error::Configuration(e.to_string())
+    }
+}
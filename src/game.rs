@@ -1,66 +1,1159 @@
-use crate::{Board, Configuration, Dealer, Player};
-use itertools::Itertools;
+use crate::clock::{Answer, Clock, Decision};
+use crate::dealer::seeded_rng;
+use crate::end_condition::{EndCondition, ScoreThreshold, UniqueWinner};
+use crate::game_result::Standing;
+use crate::observer::{GameEvent, GameObserver};
+use crate::player::{AiPlayer, Id, PlayerView};
+use crate::{Board, Card, Configuration, Dealer, Error, GameResult, Player, ScoreSheet, StackId};
+use crate::{Fallback, Offense, Penalty, TimeLimit, Violation};
+use rand::{self, Rng, StdRng};
+use std::collections::VecDeque;
+use std::{error, fmt, mem, panic, slice, thread};
+
+/// What a game is waiting for, returned by `Game::step`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    /// The players with the given ids must each submit a card to play.
+    NeedPlay(Vec<Id>),
+    /// The given card of the player with the given id is lower than every
+    /// stack, so they must submit the stack they take.
+    NeedChoice(Id, Card),
+    /// No round is being played. The next step starts a round, or ends
+    /// the game.
+    RoundOver,
+    /// The game is over.
+    GameOver,
+}
+
+/// An action a player submits to a game, see `Game::submit`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Action {
+    /// The player with the given id plays the given card from their hand.
+    Play(Id, Card),
+    /// The player with the given id takes the given stack.
+    Choose(Id, StackId),
+}
+
+/// An action the game can't accept.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ActionError {
+    /// There is no player with the given id in the game.
+    UnknownPlayer(Id),
+    /// The game isn't waiting for this action from the given player.
+    NotWaiting(Id),
+    /// The given card isn't in the player's hand.
+    NotInHand(Id, Card),
+    /// The given stack isn't on the board.
+    NoSuchStack(Id, StackId),
+}
+
+impl fmt::Display for ActionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            ActionError::UnknownPlayer(id) => write!(f, "there is no player {}", id),
+            ActionError::NotWaiting(id) => write!(f, "not waiting on this from player {}", id),
+            ActionError::NotInHand(id, ref card) => {
+                write!(f, "player {} doesn't hold {}", id, card)
+            }
+            ActionError::NoSuchStack(id, stack) => {
+                write!(
+                    f,
+                    "player {} chose stack {} which isn't on the board",
+                    id, stack
+                )
+            }
+        }
+    }
+}
+
+impl error::Error for ActionError {}
+
+/// Where a game is between steps.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) enum Phase {
+    /// The game hasn't started.
+    New,
+    /// No round is being played.
+    Between,
+    /// The cards played this turn, by seat, while waiting on the rest.
+    Playing(Vec<Option<Card>>),
+    /// The played cards left to place, lowest first, and the choice
+    /// submitted for the first of them.
+    Placing(VecDeque<(Card, Id)>, Option<StackId>),
+    /// The game is over.
+    Over,
+}
 
 /// Running of the game of take5.
+///
+/// Every game owns its own `Configuration`, so games with different rules
+/// can be run side by side. By default the game ends once a player reaches
+/// the configuration's `win_score` and a single player has the lowest
+/// score, see `end_condition` for other ways to end a game. Anything
+/// watching the game can be added to `observers`, see `GameObserver`.
+///
+/// A game can be run to the end with `run`, which asks each player for
+/// their plays and choices. Otherwise it can be driven one `step` at a
+/// time, submitting the actions it waits for from anywhere.
+///
+/// # Examples
+///
+/// ```
+/// use std::thread;
+/// use take5::{Configuration, Game};
+/// use take5::dealer::StandardDealer;
+/// use take5::player::{AiPlayer, Player};
+///
+/// let handles: Vec<_> = [(10, 5, 66), (8, 6, 33)].iter().map(|&(turns, size, score)| {
+///     thread::spawn(move || {
+///         let config = Configuration {
+///             turn_count: turns,
+///             stack_size: size,
+///             win_score: score,
+///             ..Configuration::default()
+///         };
+///         let players = (0..4).map(|id| {
+///             Box::new(AiPlayer::new(id)) as Box<dyn Player>
+///         }).collect();
+///         let mut game = Game::with_configuration(
+///             config.clone(),
+///             Box::new(StandardDealer::default()),
+///             players,
+///         );
+///         game.run();
+///         assert_eq!(game.configuration, config);
+///         assert!(game.players.iter().any(|p| p.score() >= score));
+///     })
+/// }).collect();
+/// for handle in handles {
+///     handle.join().unwrap();
+/// }
+/// ```
 pub struct Game {
+    pub configuration: Configuration,
     pub board: Board,
     pub dealer: Box<dyn Dealer>,
     pub players: Vec<Box<dyn Player>>,
+    pub end_condition: Box<dyn EndCondition>,
+    pub scores: ScoreSheet,
+    pub observers: Vec<Box<dyn GameObserver>>,
+    pub(crate) phase: Phase,
+    pub(crate) hands: Vec<Vec<Card>>,
+    pub(crate) before: Vec<u32>,
+    pub(crate) turn: usize,
+    pub(crate) violations: Vec<Violation>,
+    pub(crate) history: Vec<GameEvent>,
+    pub(crate) clocks: Vec<Clock>,
+    pub(crate) time_limit: TimeLimit,
+    /// The picks recorded for penalized players, which a replay makes
+    /// again instead of drawing new ones.
+    pub(crate) substitutes: VecDeque<Action>,
+    rng: StdRng,
 }
 
 impl Game {
-    /// Creates a new game, parsing arguments from the command line.
+    /// Creates a new game with the default configuration.
     pub fn new(dealer: Box<dyn Dealer>, players: Vec<Box<dyn Player>>) -> Self {
-        let board = Board::default();
+        Game::with_configuration(Configuration::default(), dealer, players)
+    }
+
+    /// Creates a new game played with the given configuration.
+    pub fn with_configuration(
+        configuration: Configuration,
+        dealer: Box<dyn Dealer>,
+        players: Vec<Box<dyn Player>>,
+    ) -> Self {
         Game {
-            board: board,
-            dealer: dealer,
-            players: players,
+            board: Board::new(configuration.stack_count),
+            end_condition: Box::new(UniqueWinner(ScoreThreshold(configuration.win_score))),
+            scores: ScoreSheet::new(players.iter().map(|p| p.id()).collect()),
+            observers: Vec::new(),
+            phase: Phase::New,
+            hands: vec![Vec::new(); players.len()],
+            before: Vec::new(),
+            turn: 0,
+            violations: Vec::new(),
+            history: Vec::new(),
+            clocks: players.iter().map(|_| Clock::default()).collect(),
+            time_limit: configuration.time_limit,
+            substitutes: VecDeque::new(),
+            rng: seeded_rng(dealer.seed().unwrap_or_else(|| rand::thread_rng().gen())),
+            configuration,
+            dealer,
+            players,
+        }
+    }
+
+    /// Run the game, returning its result, as `try_run`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the game fails, see `Game::step`.
+    pub fn run(&mut self) -> GameResult {
+        self.try_run().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Run the game, returning its result.
+    ///
+    /// Each player is asked for their plays and choices in turn, and
+    /// submits them to the game until it's over. Players breaking the
+    /// rules are penalized, see `Game::penalize`. Every player is told
+    /// when the game and each of its rounds start and end, see
+    /// `Player::start_game`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the game fails, see `Game::step`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use take5::{Configuration, Error, Game, Player};
+    /// use take5::dealer::StandardDealer;
+    /// use take5::player::AiPlayer;
+    ///
+    /// // The standard dealer's cards have up to 7 bulls.
+    /// let config = Configuration { bull_range: (1, 5), ..Configuration::default() };
+    /// let players = (0..2).map(|id| {
+    ///     Box::new(AiPlayer::new(id)) as Box<dyn Player>
+    /// }).collect();
+    /// let mut game = Game::with_configuration(config, Box::new(StandardDealer::default()), players);
+    /// match game.try_run() {
+    ///     Err(Error::BullRange { bull, .. }) => assert_eq!(bull, 7),
+    ///     _ => panic!("expected a bull out of range"),
+    /// }
+    /// ```
+    pub fn try_run(&mut self) -> Result<GameResult, Error> {
+        loop {
+            match self.step()? {
+                Status::GameOver => return Ok(self.result()),
+                status => self.ask_players(&status)?,
+            }
         }
     }
 
-    /// Run the game.
-    pub fn run(&mut self) {
-        // The rounds.
-        while self
+    /// Asks this game's players for the actions the given status waits
+    /// for, and submits them. Any board the players choose from is shown
+    /// to them first, and each player decides with their own `view` of
+    /// the game. A player who fails to decide, plays a card they
+    /// don't hold, or chooses a stack which isn't on the board is
+    /// penalized.
+    ///
+    /// As plays are simultaneous, every player who must play is asked at
+    /// once, each on their own thread. Their answers are gathered, and
+    /// then submitted in the order of the status's ids, so the outcome is
+    /// the same as asking them one at a time.
+    ///
+    /// With a `TimeLimit`, each player is asked on another thread, and
+    /// the game waits no longer than the time left on their clock. A
+    /// player who runs out of time has the limit's `fallback` decide for
+    /// them, and the time each player used is reported in the result of
+    /// the game. Until a player who ran out of time answers, a stand in
+    /// takes their seat in `players`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the game isn't waiting on the given status.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::fmt;
+    /// use std::sync::{Arc, Barrier};
+    /// use take5::{Board, Card, Game, Player, StackId};
+    /// use take5::dealer::StandardDealer;
+    /// use take5::player::{AiPlayer, Id, PlayerError, PlayerView};
+    ///
+    /// // A player who waits for every other player to be asked before
+    /// // answering.
+    /// struct Waiting(AiPlayer, Arc<Barrier>);
+    ///
+    /// impl Player for Waiting {
+    ///     fn id(&self) -> Id { self.0.id() }
+    ///     fn hand(&self) -> &[Card] { self.0.hand() }
+    ///     fn pile(&self) -> &[Card] { self.0.pile() }
+    ///     fn draw(&mut self, card: Card) { self.0.draw(card) }
+    ///     fn discard(&mut self, card: &Card) { self.0.discard(card) }
+    ///     fn look_at_board(&mut self, board: &Board) { self.0.look_at_board(board) }
+    ///     fn look_at_played(&mut self, played: &[(Card, Id)]) { self.0.look_at_played(played) }
+    ///     fn look_at_taken(&mut self, taken: (&[Card], Id)) { self.0.look_at_taken(taken) }
+    ///     fn take(&mut self, cards: Vec<Card>) { self.0.take(cards) }
+    ///     fn choose(&self, card: &Card, board: &Board, view: &PlayerView) -> Result<StackId, PlayerError> {
+    ///         self.0.choose(card, board, view)
+    ///     }
+    ///     fn play(&mut self, view: &PlayerView) -> Result<Card, PlayerError> {
+    ///         self.1.wait();
+    ///         self.0.play(view)
+    ///     }
+    /// }
+    ///
+    /// impl fmt::Display for Waiting {
+    ///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "Waiting") }
+    /// }
+    ///
+    /// let barrier = Arc::new(Barrier::new(5));
+    /// let players = (0..5).map(|id| {
+    ///     Box::new(Waiting(AiPlayer::new(id), barrier.clone())) as Box<dyn Player>
+    /// }).collect();
+    /// let mut game = Game::new(Box::new(StandardDealer::default()), players);
+    /// let status = game.step().unwrap();
+    ///
+    /// // Five players think at once, or none of them would ever answer.
+    /// game.ask_players(&status).unwrap();
+    /// assert_eq!(game.hand(0).unwrap().len(), 9);
+    /// ```
+    pub fn ask_players(&mut self, status: &Status) -> Result<(), Error> {
+        let (ids, decision) = match *status {
+            Status::NeedPlay(ref ids) => (&ids[..], Decision::Play),
+            Status::NeedChoice(ref id, ref card) => {
+                (slice::from_ref(id), Decision::Choose(card.clone()))
+            }
+            Status::RoundOver | Status::GameOver => return Ok(()),
+        };
+        let seats = ids
+            .iter()
+            .map(|&id| self.seat(id).ok_or(Error::PlayerNotFound(id)))
+            .collect::<Result<Vec<usize>, Error>>()?;
+        let answers = self.gather(&seats, &decision);
+        for ((&id, &i), answer) in ids.iter().zip(&seats).zip(answers) {
+            if let Phase::Over = self.phase {
+                break;
+            }
+            let offense = match answer {
+                Some(Answer::Play(Ok(card))) => match self.submit(Action::Play(id, card)) {
+                    Ok(()) => continue,
+                    Err(ActionError::NotInHand(_, card)) => Offense::NotInHand(card),
+                    Err(e) => return Err(e.into()),
+                },
+                Some(Answer::Choose(Ok(stack))) => match self.submit(Action::Choose(id, stack)) {
+                    Ok(()) => continue,
+                    Err(ActionError::NoSuchStack(_, stack)) => Offense::NoSuchStack(stack),
+                    Err(e) => return Err(e.into()),
+                },
+                Some(Answer::Play(Err(e))) | Some(Answer::Choose(Err(e))) => {
+                    Offense::Failed(e.reason)
+                }
+                None => match self.fall_back(i, &decision) {
+                    Some(action) => {
+                        self.submit(action)?;
+                        continue;
+                    }
+                    None => Offense::Failed("player ran out of time.".into()),
+                },
+            };
+            self.penalize(id, offense)?;
+        }
+        Ok(())
+    }
+
+    /// Shows the board to the players in the given seats, and asks them
+    /// all for a decision at once, returning their answers in the order
+    /// of the seats.
+    fn gather(&mut self, seats: &[usize], decision: &Decision) -> Vec<Option<Answer>> {
+        let mut views: Vec<PlayerView> = (0..self.players.len())
+            .filter(|i| seats.contains(i))
+            .map(|i| self.view_of(i))
+            .collect();
+        let board = &self.board;
+        let limit = &self.time_limit;
+        let mut asked: Vec<_> = self
             .players
+            .iter_mut()
+            .zip(self.clocks.iter_mut())
+            .enumerate()
+            .filter(|(i, _)| seats.contains(i))
+            .collect();
+        for (_, (player, _)) in asked.iter_mut() {
+            player.look_at_board(board);
+        }
+        if let [(_, (ref mut player, ref mut clock))] = asked[..] {
+            return vec![clock.decide(player, limit, decision.clone(), views.remove(0))];
+        }
+        let mut answers: Vec<(usize, Option<Answer>)> = thread::scope(|scope| {
+            let handles: Vec<_> = asked
+                .into_iter()
+                .zip(views)
+                .map(|((i, (player, clock)), view)| {
+                    let decision = decision.clone();
+                    (
+                        i,
+                        scope.spawn(move || clock.decide(player, limit, decision, view)),
+                    )
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|(i, handle)| match handle.join() {
+                    Ok(answer) => (i, answer),
+                    Err(e) => panic::resume_unwind(e),
+                })
+                .collect()
+        });
+        seats
             .iter()
-            .all(|p| p.score() < Configuration::win_score())
-            || self.players[0].score() == self.players[1].score()
-        {
-            self.dealer.open();
-            self.dealer.deals(&mut self.players);
-            self.dealer.flip(&mut self.board);
-
-            // The turns.
-            for _ in 0..Configuration::turn_count() {
-                let mut played = Vec::new();
-                for player in self.players.iter_mut() {
-                    player.look_at_board(&mut self.board);
-                    let card = player.play();
-                    played.push((card, player.id()));
+            .map(|i| {
+                let j = answers.iter().position(|&(k, _)| k == *i);
+                j.and_then(|j| answers.swap_remove(j).1)
+            })
+            .collect()
+    }
+
+    /// Returns the action the time limit's fallback decides on for the
+    /// player in the given seat, who ran out of time. A card played for
+    /// them is discarded from their hand.
+    fn fall_back(&mut self, seat: usize, decision: &Decision) -> Option<Action> {
+        let id = self.players[seat].id();
+        let view = self.view_of(seat);
+        let hand = &self.hands[seat];
+        let mut ai = AiPlayer::new(id);
+        match *decision {
+            Decision::Play => {
+                let card = match self.time_limit.fallback {
+                    Fallback::Ai => ai.play(&view).ok(),
+                    Fallback::Lowest => hand.iter().min().cloned(),
+                    Fallback::Random => self.rng.choose(hand).cloned(),
+                }?;
+                self.players[seat].discard(&card);
+                Some(Action::Play(id, card))
+            }
+            Decision::Choose(ref card) => {
+                let stack = match self.time_limit.fallback {
+                    Fallback::Ai => ai.choose(card, &self.board, &view).ok()?,
+                    Fallback::Lowest => StackId::A,
+                    Fallback::Random => StackId::new(self.rng.gen_range(0, self.board.len())),
+                };
+                Some(Action::Choose(id, stack))
+            }
+        }
+    }
+
+    /// Penalizes the player with the given id for the given offense,
+    /// against the action the game is waiting on from them, with the
+    /// configuration's `penalty`. The violation is reported in the
+    /// result of the game. A player to be substituted, or who forfeits,
+    /// with no card in hand or no stack to take, is ejected instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the player isn't in the game, or the game isn't
+    /// waiting on an action from them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use take5::{Configuration, Game, Offense, Penalty, Player, Status};
+    /// use take5::dealer::StandardDealer;
+    /// use take5::player::AiPlayer;
+    ///
+    /// let config = Configuration { penalty: Penalty::Eject, ..Configuration::default() };
+    /// let players = (0..3).map(|id| {
+    ///     Box::new(AiPlayer::new(id)) as Box<dyn Player>
+    /// }).collect();
+    /// let mut game = Game::with_configuration(config, Box::new(StandardDealer::default()), players);
+    /// game.step().unwrap();
+    /// let offense = Offense::Failed("disconnected".into());
+    /// game.penalize(1, offense).unwrap();
+    /// assert_eq!(game.step(), Ok(Status::NeedPlay(vec![0, 2])));
+    ///
+    /// let result = game.run();
+    /// assert_eq!(result.violations[0].player, 1);
+    /// assert_eq!(result.standings[2].id, 1);
+    /// assert!(result.standings[2].disqualified);
+    /// ```
+    ///
+    /// An ejected player is dealt no more cards, and the deck is sized
+    /// for the players left.
+    ///
+    /// ```
+    /// use take5::{Configuration, Game, Offense, Penalty, Player, Status};
+    /// use take5::dealer::StandardDealer;
+    /// use take5::player::AiPlayer;
+    ///
+    /// let config = Configuration {
+    ///     tactical: true,
+    ///     penalty: Penalty::Eject,
+    ///     ..Configuration::default()
+    /// };
+    /// let players = (0..3).map(|id| {
+    ///     Box::new(AiPlayer::new(id)) as Box<dyn Player>
+    /// }).collect();
+    /// let mut game = Game::with_configuration(config, Box::new(StandardDealer::seeded(1)), players);
+    /// game.step().unwrap();
+    /// game.penalize(1, Offense::Failed("disconnected".into())).unwrap();
+    /// loop {
+    ///     match game.step().unwrap() {
+    ///         Status::RoundOver => break,
+    ///         status => game.ask_players(&status).unwrap(),
+    ///     }
+    /// }
+    /// game.step().unwrap();
+    /// assert!(game.players[1].hand().is_empty());
+    ///
+    /// // Player 0 has seen every card but the other player's hand.
+    /// assert_eq!(game.view(0).unwrap().unseen(), 10);
+    /// ```
+    ///
+    /// A substituted card is played from the player's own hand, as if
+    /// they had played it.
+    ///
+    /// ```
+    /// use take5::{Configuration, Game, Offense, Penalty, Player, Status};
+    /// use take5::dealer::StandardDealer;
+    /// use take5::player::AiPlayer;
+    ///
+    /// let config = Configuration { penalty: Penalty::Substitute, ..Configuration::default() };
+    /// let players = (0..3).map(|id| {
+    ///     Box::new(AiPlayer::new(id)) as Box<dyn Player>
+    /// }).collect();
+    /// let mut game = Game::with_configuration(config, Box::new(StandardDealer::default()), players);
+    /// game.step().unwrap();
+    /// assert_eq!(game.players[1].hand().len(), 10);
+    /// game.penalize(1, Offense::Failed("disconnected".into())).unwrap();
+    /// assert_eq!(game.players[1].hand().len(), 9);
+    /// assert_eq!(game.step(), Ok(Status::NeedPlay(vec![0, 2])));
+    /// ```
+    pub fn penalize(&mut self, id: Id, offense: Offense) -> Result<(), ActionError> {
+        let seat = self.seat(id).ok_or(ActionError::UnknownPlayer(id))?;
+        let waiting = match self.phase {
+            Phase::Playing(ref played) => played[seat].is_none() && !self.is_ejected(id),
+            Phase::Placing(ref pending, None) => match pending.front() {
+                Some((card, chooser)) => *chooser == id && self.needs_choice(card) == Ok(true),
+                None => false,
+            },
+            _ => false,
+        };
+        if !waiting {
+            return Err(ActionError::NotWaiting(id));
+        }
+
+        let mut penalty = self.configuration.penalty;
+        if penalty != Penalty::Eject {
+            // Nothing can be picked for the player, so they're ejected.
+            let empty = match self.phase {
+                Phase::Playing(_) => self.hands[seat].is_empty(),
+                _ => self.board.is_empty(),
+            };
+            if empty {
+                penalty = Penalty::Eject;
+            }
+        }
+        let violation = Violation {
+            player: id,
+            round: self.scores.round_count(),
+            turn: self.turn,
+            offense,
+            penalty,
+        };
+        self.violations.push(violation.clone());
+        // A player who forfeits is ranked last, but their cards are still
+        // played, so the game goes on for everyone else.
+        let substitute = match penalty {
+            Penalty::Substitute | Penalty::Forfeit => Some(self.substitute(seat)),
+            Penalty::Eject => None,
+        };
+        self.notify(&GameEvent::PlayerPenalized {
+            violation,
+            substitute: substitute.clone(),
+        });
+        match substitute {
+            Some(action) => {
+                if let Action::Play(_, ref card) = action {
+                    self.players[seat].discard(card);
+                }
+                self.submit(action)
+            }
+            None => {
+                for card in mem::take(&mut self.hands[seat]) {
+                    self.players[seat].discard(&card);
+                }
+                if let Phase::Placing(ref mut pending, _) = self.phase {
+                    pending.pop_front();
                 }
+                Ok(())
+            }
+        }
+    }
+
+    /// Picks a random card or stack for the player in the given seat, or
+    /// the one recorded for them when replaying, as long as it's legal.
+    fn substitute(&mut self, seat: usize) -> Action {
+        let id = self.players[seat].id();
+        let recorded = self.substitutes.pop_front().filter(|action| match *action {
+            Action::Play(player, ref card) => {
+                player == id
+                    && matches!(self.phase, Phase::Playing(_))
+                    && self.hands[seat].contains(card)
+            }
+            Action::Choose(player, stack) => {
+                player == id
+                    && matches!(self.phase, Phase::Placing(..))
+                    && stack.index() < self.board.len()
+            }
+        });
+        recorded.unwrap_or_else(|| match self.phase {
+            Phase::Playing(_) => {
+                let hand = &self.hands[seat];
+                Action::Play(id, hand[self.rng.gen_range(0, hand.len())].clone())
+            }
+            _ => Action::Choose(id, StackId::new(self.rng.gen_range(0, self.board.len()))),
+        })
+    }
 
-                for player in self.players.iter_mut() {
-                    player.look_at_played(&played);
+    /// Advance the game as far as it can go, returning what it's waiting
+    /// for next. Stepping a game which is waiting on actions which haven't
+    /// been submitted changes nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the dealer fails to deal or place the cards. The
+    /// game is over after an error, and every later step returns
+    /// `Status::GameOver`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use take5::{Action, Game, Player, Status};
+    /// use take5::dealer::StandardDealer;
+    /// use take5::player::AiPlayer;
+    ///
+    /// let players = (0..3).map(|id| {
+    ///     Box::new(AiPlayer::new(id)) as Box<dyn Player>
+    /// }).collect();
+    /// let mut game = Game::new(Box::new(StandardDealer::default()), players);
+    /// loop {
+    ///     match game.step().unwrap() {
+    ///         // Everyone plays the first card in their hand.
+    ///         Status::NeedPlay(ids) => for id in ids {
+    ///             let card = game.hand(id).unwrap()[0].clone();
+    ///             game.submit(Action::Play(id, card)).unwrap();
+    ///         },
+    ///         // And always takes the last stack.
+    ///         Status::NeedChoice(id, _) => {
+    ///             let stack = game.board.ids().last().unwrap();
+    ///             game.submit(Action::Choose(id, stack)).unwrap();
+    ///         }
+    ///         Status::RoundOver => println!("{:?}", game.scores.totals()),
+    ///         Status::GameOver => break,
+    ///     }
+    /// }
+    /// assert!(game.result().rounds >= 1);
+    /// ```
+    ///
+    /// Each round ends with `Status::RoundOver`, and the next round is only
+    /// dealt on the step after.
+    ///
+    /// ```
+    /// use take5::{Action, Game, Player, Status};
+    /// use take5::dealer::StandardDealer;
+    /// use take5::player::AiPlayer;
+    ///
+    /// let players = (0..3).map(|id| {
+    ///     Box::new(AiPlayer::new(id)) as Box<dyn Player>
+    /// }).collect();
+    /// let mut game = Game::new(Box::new(StandardDealer::seeded(1)), players);
+    /// let mut turns = 0;
+    /// loop {
+    ///     match game.step().unwrap() {
+    ///         Status::NeedPlay(ids) => {
+    ///             turns += 1;
+    ///             for id in ids {
+    ///                 let card = game.hand(id).unwrap()[0].clone();
+    ///                 game.submit(Action::Play(id, card)).unwrap();
+    ///             }
+    ///         }
+    ///         Status::NeedChoice(id, _) => {
+    ///             let stack = game.board.ids().last().unwrap();
+    ///             game.submit(Action::Choose(id, stack)).unwrap();
+    ///         }
+    ///         Status::RoundOver => break,
+    ///         Status::GameOver => unreachable!(),
+    ///     }
+    /// }
+    /// assert_eq!(turns, 10);
+    /// assert!(game.hand(0).unwrap().is_empty());
+    /// assert!(matches!(game.step().unwrap(), Status::NeedPlay(_)));
+    /// assert_eq!(game.hand(0).unwrap().len(), 10);
+    /// ```
+    pub fn step(&mut self) -> Result<Status, Error> {
+        loop {
+            match mem::replace(&mut self.phase, Phase::Over) {
+                Phase::New => {
+                    self.notify(&GameEvent::GameStarted {
+                        configuration: self.configuration.clone(),
+                        players: self
+                            .players
+                            .iter()
+                            .map(|p| (p.id(), p.to_string()))
+                            .collect(),
+                        seed: self.dealer.seed(),
+                    });
+                    let seats: Vec<Id> = self.players.iter().map(|p| p.id()).collect();
+                    for player in self.players.iter_mut() {
+                        player.start_game(&seats);
+                    }
+                    self.phase = Phase::Between;
                 }
+                Phase::Between => {
+                    if self.is_over() {
+                        self.end_game();
+                        return Ok(Status::GameOver);
+                    }
+                    self.start_round()?;
+                    self.phase = Phase::Playing(vec![None; self.players.len()]);
+                }
+                Phase::Playing(played) => {
+                    let ids: Vec<Id> = self
+                        .players
+                        .iter()
+                        .zip(&played)
+                        .filter(|&(p, c)| c.is_none() && !self.is_ejected(p.id()))
+                        .map(|(p, _)| p.id())
+                        .collect();
+                    if !ids.is_empty() {
+                        self.phase = Phase::Playing(played);
+                        return Ok(Status::NeedPlay(ids));
+                    }
+                    let played: Vec<(Card, Id)> = played
+                        .into_iter()
+                        .zip(self.players.iter().map(|p| p.id()))
+                        .filter_map(|(c, id)| c.map(|c| (c, id)))
+                        .collect();
+                    for player in self.players.iter_mut() {
+                        player.look_at_played(&played);
+                    }
+                    self.notify(&GameEvent::CardsRevealed {
+                        played: played.clone(),
+                    });
+                    let mut pending: Vec<(Card, Id)> = played;
+                    pending.sort_by(|a, b| a.0.cmp(&b.0));
+                    self.phase = Phase::Placing(pending.into(), None);
+                }
+                Phase::Placing(mut pending, choice) => {
+                    let (card, id) = match pending.pop_front() {
+                        Some(next) => next,
+                        None => {
+                            self.end_turn()?;
+                            // The next round starts on the next step.
+                            if let Phase::Between = self.phase {
+                                if !self.is_over() {
+                                    return Ok(Status::RoundOver);
+                                }
+                            }
+                            continue;
+                        }
+                    };
+                    if choice.is_none() && self.needs_choice(&card)? {
+                        pending.push_front((card.clone(), id));
+                        self.phase = Phase::Placing(pending, None);
+                        return Ok(Status::NeedChoice(id, card));
+                    }
+                    self.dealer.place(
+                        &mut self.board,
+                        (card, id),
+                        choice,
+                        &mut self.players,
+                        &self.configuration,
+                        &mut Public {
+                            history: &mut self.history,
+                            observers: &mut self.observers,
+                        },
+                    )?;
+                    self.phase = Phase::Placing(pending, None);
+                }
+                Phase::Over => return Ok(Status::GameOver),
+            }
+        }
+    }
 
-                self.dealer
-                    .stack(&mut self.board, played, &mut self.players);
+    /// Submit an action the game is waiting for. The action takes effect
+    /// on the next `step`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the player isn't in the game, the game isn't
+    /// waiting on this action from them, or the action isn't legal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use take5::{Action, ActionError, Game, Player, StackId, Status};
+    /// use take5::dealer::StandardDealer;
+    /// use take5::player::AiPlayer;
+    ///
+    /// let players = (0..2).map(|id| {
+    ///     Box::new(AiPlayer::new(id)) as Box<dyn Player>
+    /// }).collect();
+    /// let mut game = Game::new(Box::new(StandardDealer::default()), players);
+    /// assert_eq!(game.step(), Ok(Status::NeedPlay(vec![0, 1])));
+    ///
+    /// let card = game.hand(0).unwrap()[0].clone();
+    /// assert_eq!(
+    ///     game.submit(Action::Play(1, card.clone())),
+    ///     Err(ActionError::NotInHand(1, card.clone())),
+    /// );
+    /// assert_eq!(game.submit(Action::Play(0, card.clone())), Ok(()));
+    /// assert_eq!(game.submit(Action::Play(0, card)), Err(ActionError::NotWaiting(0)));
+    /// assert_eq!(
+    ///     game.submit(Action::Choose(1, StackId::A)),
+    ///     Err(ActionError::NotWaiting(1)),
+    /// );
+    /// assert_eq!(game.step(), Ok(Status::NeedPlay(vec![1])));
+    /// ```
+    pub fn submit(&mut self, action: Action) -> Result<(), ActionError> {
+        let id = match action {
+            Action::Play(id, _) | Action::Choose(id, _) => id,
+        };
+        let seat = self.seat(id).ok_or(ActionError::UnknownPlayer(id))?;
+        if self.is_ejected(id) {
+            return Err(ActionError::NotWaiting(id));
+        }
+        match action {
+            Action::Play(_, card) => {
+                let played = match self.phase {
+                    Phase::Playing(ref mut played) if played[seat].is_none() => played,
+                    _ => return Err(ActionError::NotWaiting(id)),
+                };
+                let hand = &mut self.hands[seat];
+                let i = hand
+                    .iter()
+                    .position(|c| *c == card)
+                    .ok_or_else(|| ActionError::NotInHand(id, card.clone()))?;
+                hand.remove(i);
+                played[seat] = Some(card);
+                Ok(())
             }
+            Action::Choose(_, stack) => {
+                let waiting = match self.phase {
+                    Phase::Placing(ref pending, None) => match pending.front() {
+                        Some((card, chooser)) => {
+                            *chooser == id && self.needs_choice(card) == Ok(true)
+                        }
+                        None => false,
+                    },
+                    _ => false,
+                };
+                if !waiting {
+                    return Err(ActionError::NotWaiting(id));
+                }
+                if self.board.get(stack).is_none() {
+                    return Err(ActionError::NoSuchStack(id, stack));
+                }
+                if let Phase::Placing(_, ref mut choice) = self.phase {
+                    *choice = Some(stack);
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns the cards the player with the given id has yet to play this
+    /// round, or `None` if there's no such player.
+    pub fn hand(&self, id: Id) -> Option<&[Card]> {
+        self.seat(id).map(|i| &self.hands[i][..])
+    }
+
+    /// Returns what the player with the given id may know about the game,
+    /// or `None` if there's no such player. This is the view they're given
+    /// when asked for a decision, see `PlayerView`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use take5::{Game, Player};
+    /// use take5::dealer::StandardDealer;
+    /// use take5::observer::GameEvent;
+    /// use take5::player::AiPlayer;
+    ///
+    /// let players = (0..3).map(|id| {
+    ///     Box::new(AiPlayer::new(id)) as Box<dyn Player>
+    /// }).collect();
+    /// let mut game = Game::new(Box::new(StandardDealer::seeded(1)), players);
+    /// game.step().unwrap();
+    ///
+    /// // The dealer's seed is kept from every player.
+    /// for id in 0..3 {
+    ///     let view = game.view(id).unwrap();
+    ///     match view.history()[0] {
+    ///         GameEvent::GameStarted { seed, .. } => assert_eq!(seed, None),
+    ///         ref event => panic!("unexpected {:?}", event),
+    ///     }
+    /// }
+    /// ```
+    pub fn view(&self, id: Id) -> Option<PlayerView> {
+        self.seat(id).map(|i| self.view_of(i))
+    }
+
+    /// Returns the view of the player in the given seat.
+    fn view_of(&self, seat: usize) -> PlayerView {
+        PlayerView {
+            id: self.players[seat].id(),
+            hand: self.hands[seat].clone(),
+            pile: self.players[seat].pile().to_vec(),
+            board: self.board.clone(),
+            configuration: self.configuration.clone(),
+            scores: self.players.iter().map(|p| (p.id(), p.score())).collect(),
+            history: self.history.clone(),
+            deck: self
+                .configuration
+                .for_players(self.seated())
+                .map_or(0, |config| config.deck_size as usize),
+        }
+    }
+
+    /// Notifies the observers of the given event, keeping it in the
+    /// history of the game if it's public.
+    fn notify(&mut self, event: &GameEvent) {
+        Public {
+            history: &mut self.history,
+            observers: &mut self.observers,
+        }
+        .notify(event);
+    }
+
+    /// Starts a new round, dealing the players' hands and flipping the
+    /// board.
+    fn start_round(&mut self) -> Result<(), Error> {
+        let round = self.scores.round_count();
+        self.notify(&GameEvent::RoundStarted { round });
+        for player in self.players.iter_mut() {
+            player.start_round(round);
+        }
+        self.before = self.players.iter().map(|p| p.score()).collect();
+        self.dealer
+            .open(&self.configuration.for_players(self.seated())?)?;
+        let mut deck = self.dealer.deck().to_vec();
+        deck.sort();
+        for player in self.players.iter_mut() {
+            player.look_at_deck(&deck);
+        }
+        // Players driven by `submit` may still hold cards from past rounds,
+        // so only the newly dealt cards are their hand.
+        let held: Vec<Vec<Card>> = self.players.iter().map(|p| p.hand().to_vec()).collect();
+        // Ejected players are dealt nothing, and the rest keep their seats.
+        let (seated, ejected): (Vec<_>, Vec<_>) = mem::take(&mut self.players)
+            .into_iter()
+            .enumerate()
+            .partition(|(_, p)| !self.is_ejected(p.id()));
+        let seats: Vec<usize> = seated.iter().map(|s| s.0).collect();
+        let mut dealt: Vec<Box<dyn Player>> = seated.into_iter().map(|s| s.1).collect();
+        let deals = self.dealer.deals(&mut dealt, &self.configuration);
+        let mut players: Vec<_> = seats.into_iter().zip(dealt).chain(ejected).collect();
+        players.sort_by_key(|p| p.0);
+        self.players = players.into_iter().map(|p| p.1).collect();
+        deals?;
+        self.hands = self
+            .players
+            .iter()
+            .zip(held)
+            .map(|(p, mut held)| {
+                let mut hand = p.hand().to_vec();
+                hand.retain(|c| match held.iter().position(|h| h == c) {
+                    Some(i) => {
+                        held.remove(i);
+                        false
+                    }
+                    None => true,
+                });
+                hand
+            })
+            .collect();
+        let hands = self
+            .players
+            .iter()
+            .zip(&self.hands)
+            .map(|(p, hand)| (p.id(), hand.clone()))
+            .collect();
+        self.notify(&GameEvent::HandsDealt { hands });
+        self.dealer.flip(&mut self.board, &self.configuration)?;
+        self.notify(&GameEvent::BoardFlipped {
+            board: self.board.clone(),
+        });
+        self.turn = 0;
+        Ok(())
+    }
+
+    /// Ends a turn once every card is placed, ending the round after the
+    /// last turn.
+    fn end_turn(&mut self) -> Result<(), Error> {
+        self.turn += 1;
+        if self.turn < self.configuration.turn_count {
+            self.phase = Phase::Playing(vec![None; self.players.len()]);
+            Ok(())
+        } else {
+            self.end_round()
+        }
+    }
+
+    /// Ends a round, scoring the bulls each player took in it.
+    fn end_round(&mut self) -> Result<(), Error> {
+        let round = self.scores.round_count();
+        let scores: Vec<(Id, u32)> = self
+            .players
+            .iter()
+            .zip(&self.before)
+            .map(|(p, score)| (p.id(), p.score().saturating_sub(*score)))
+            .collect();
+        self.scores
+            .record(scores.iter().map(|&(_, score)| score).collect())?;
+        for player in self.players.iter_mut() {
+            player.end_round(round, &scores);
+        }
+        self.notify(&GameEvent::RoundEnded { round, scores });
+        self.phase = Phase::Between;
+        Ok(())
+    }
+
+    /// Ends the game, telling the players and observers its result.
+    fn end_game(&mut self) {
+        let result = self.result();
+        for player in self.players.iter_mut() {
+            player.end_game(&result);
+        }
+        self.notify(&GameEvent::GameEnded { result });
+    }
+
+    /// Returns true when no round is left to play, as every player was
+    /// ejected or the end condition is met. Disqualified players are left
+    /// out of the end condition, as they can't win.
+    fn is_over(&self) -> bool {
+        self.seated() == 0 || self.end_condition.is_over(&self.qualified_scores())
+    }
+
+    /// Returns the number of players who weren't ejected.
+    fn seated(&self) -> usize {
+        self.players
+            .iter()
+            .filter(|p| !self.is_ejected(p.id()))
+            .count()
+    }
+
+    /// Returns the scores of the players who weren't disqualified.
+    fn qualified_scores(&self) -> ScoreSheet {
+        let disqualified: Vec<Id> = self
+            .violations
+            .iter()
+            .filter(|v| v.disqualifies())
+            .map(|v| v.player)
+            .collect();
+        self.scores.without(&disqualified)
+    }
+
+    /// Returns true when the given card doesn't fit on any stack.
+    fn needs_choice(&self, card: &Card) -> Result<bool, Error> {
+        let position = self
+            .dealer
+            .placement(&self.configuration)
+            .place(&self.board, card)?;
+        Ok(position.is_none())
+    }
 
-            self.players.sort_by(|a, b| a.score().cmp(&b.score()));
+    /// Returns true when the player with the given id was ejected.
+    fn is_ejected(&self, id: Id) -> bool {
+        self.violations
+            .iter()
+            .any(|v| v.player == id && v.penalty == Penalty::Eject)
+    }
+
+    /// Returns the seat of the player with the given id.
+    fn seat(&self, id: Id) -> Option<usize> {
+        self.players.iter().position(|p| p.id() == id)
+    }
+
+    /// Returns the result of the game as it stands.
+    pub fn result(&self) -> GameResult {
+        let standings = self
+            .players
+            .iter()
+            .zip(&self.clocks)
+            .map(|(p, clock)| Standing {
+                rank: 0,
+                id: p.id(),
+                name: p.to_string(),
+                score: p.score(),
+                pile: p.pile().to_vec(),
+                disqualified: self
+                    .violations
+                    .iter()
+                    .any(|v| v.player == p.id() && v.disqualifies()),
+                time: if self.time_limit.is_limited() {
+                    Some(clock.used)
+                } else {
+                    None
+                },
+            })
+            .collect();
+        GameResult {
+            seed: self.dealer.seed(),
+            violations: self.violations.clone(),
+            ..GameResult::new(standings, self.scores.clone())
         }
     }
 
+    /// Returns the ids of the players with the lowest score, leaving out
+    /// disqualified players. When more than one player is returned the
+    /// game is tied between them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use take5::{Configuration, Game, Offense, Penalty, Player};
+    /// use take5::dealer::StandardDealer;
+    /// use take5::player::AiPlayer;
+    ///
+    /// let config = Configuration { penalty: Penalty::Eject, ..Configuration::default() };
+    /// let players = (0..3).map(|id| {
+    ///     Box::new(AiPlayer::new(id)) as Box<dyn Player>
+    /// }).collect();
+    /// let mut game = Game::with_configuration(config, Box::new(StandardDealer::seeded(1)), players);
+    /// game.step().unwrap();
+    /// game.penalize(1, Offense::Failed("disconnected".into())).unwrap();
+    ///
+    /// // Player 1 takes no bulls once ejected, but can't win.
+    /// let result = game.run();
+    /// assert_eq!(result.scores.total(1), 0);
+    /// assert!(!game.winners().contains(&1));
+    /// assert_eq!(game.winners(), result.winners());
+    /// ```
+    pub fn winners(&self) -> Vec<Id> {
+        self.qualified_scores().leaders()
+    }
+
     /// Report on the game to stdout.
-    pub fn report(&mut self) {
-        println!(
-            "Game ended with: {}",
-            self.players
-                .iter()
-                .map(|p| format!("{} at {}", p, p.score()))
-                .join(", ")
-        );
+    pub fn report(&self) {
+        println!("{}", self.result());
+    }
+}
+
+/// The observers of a game, along with its public history.
+struct Public<'a> {
+    history: &'a mut Vec<GameEvent>,
+    observers: &'a mut Vec<Box<dyn GameObserver>>,
+}
+
+impl GameObserver for Public<'_> {
+    fn notify(&mut self, event: &GameEvent) {
+        match *event {
+            GameEvent::HandsDealt { .. } => {}
+            // The dealer's seed would give away every hand to come.
+            GameEvent::GameStarted {
+                ref configuration,
+                ref players,
+                ..
+            } => self.history.push(GameEvent::GameStarted {
+                configuration: configuration.clone(),
+                players: players.clone(),
+                seed: None,
+            }),
+            // The card picked for the player is revealed with the others.
+            GameEvent::PlayerPenalized {
+                ref violation,
+                substitute: Some(Action::Play(..)),
+            } => self.history.push(GameEvent::PlayerPenalized {
+                violation: violation.clone(),
+                substitute: None,
+            }),
+            _ => self.history.push(event.clone()),
+        }
+        self.observers.notify(event);
     }
 }
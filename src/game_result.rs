@@ -0,0 +1,150 @@
+use crate::player::Id;
+use crate::{Card, ScoreSheet, Violation};
+use itertools::Itertools;
+use std::fmt;
+use std::time::Duration;
+
+/// A player's final place in a game.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Standing {
+    /// The player's rank, starting from 1. Tied players share a rank, and
+    /// the following rank is skipped for each of them, e.g. `1, 1, 3`.
+    /// Disqualified players are ranked after every other player.
+    pub rank: usize,
+    /// The player's id.
+    pub id: Id,
+    /// The player's name, as displayed.
+    pub name: String,
+    /// The player's final score.
+    pub score: u32,
+    /// Every card the player took during the game.
+    pub pile: Vec<Card>,
+    /// True when the player was disqualified for breaking the rules.
+    #[serde(default)]
+    pub disqualified: bool,
+    /// The time the player took to decide over the game, when the game
+    /// had a `TimeLimit`.
+    #[serde(default)]
+    pub time: Option<Duration>,
+}
+
+/// The outcome of a game, returned by `Game::run`.
+///
+/// # Examples
+///
+/// ```
+/// use take5::Game;
+/// use take5::dealer::StandardDealer;
+/// use take5::player::{AiPlayer, Player};
+///
+/// let players = (0..3).map(|id| {
+///     Box::new(AiPlayer::new(id)) as Box<dyn Player>
+/// }).collect();
+/// let mut game = Game::new(Box::new(StandardDealer::default()), players);
+/// let result = game.run();
+/// assert_eq!(result.standings[0].rank, 1);
+/// assert_eq!(result.rounds, result.scores.round_count());
+/// for standing in &result.standings {
+///     assert_eq!(standing.score, result.scores.total(standing.id));
+/// }
+/// println!("{}", result);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameResult {
+    /// The final standings, from first to last place.
+    pub standings: Vec<Standing>,
+    /// The bulls each player took in every round.
+    pub scores: ScoreSheet,
+    /// The number of rounds played.
+    pub rounds: usize,
+    /// The seed the dealer shuffled with, if it has one.
+    #[serde(default)]
+    pub seed: Option<u64>,
+    /// Every rule a player broke, in the order they were broken.
+    #[serde(default)]
+    pub violations: Vec<Violation>,
+}
+
+impl GameResult {
+    /// Creates a result from the given standings and the game's scores.
+    /// The standings are sorted and ranked by their scores, after any
+    /// disqualified players.
+    pub fn new(mut standings: Vec<Standing>, scores: ScoreSheet) -> Self {
+        standings.sort_by_key(|s| (s.disqualified, s.score));
+        let all: Vec<(bool, u32)> = standings
+            .iter()
+            .map(|s| (s.disqualified, s.score))
+            .collect();
+        for standing in standings.iter_mut() {
+            let key = (standing.disqualified, standing.score);
+            standing.rank = 1 + all.iter().filter(|&&s| s < key).count();
+        }
+        GameResult {
+            standings,
+            rounds: scores.round_count(),
+            scores,
+            seed: None,
+            violations: Vec::new(),
+        }
+    }
+
+    /// Returns the ids of the players in first place. When more than one
+    /// player is returned the game is tied between them.
+    pub fn winners(&self) -> Vec<Id> {
+        self.standings
+            .iter()
+            .filter(|s| s.rank == 1)
+            .map(|s| s.id)
+            .collect()
+    }
+
+    /// Returns true when more than one player is in first place.
+    pub fn is_tie(&self) -> bool {
+        self.winners().len() > 1
+    }
+}
+
+impl fmt::Display for GameResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        if self.is_tie() {
+            writeln!(
+                f,
+                "Game tied between: {}",
+                self.standings
+                    .iter()
+                    .filter(|s| s.rank == 1)
+                    .map(|s| &s.name)
+                    .join(", ")
+            )?;
+        }
+        write!(
+            f,
+            "Game ended with: {}",
+            self.standings
+                .iter()
+                .map(|s| if s.disqualified {
+                    format!("{} at {} (disqualified)", s.name, s.score)
+                } else {
+                    format!("{} at {}", s.name, s.score)
+                })
+                .join(", ")
+        )?;
+        for violation in &self.violations {
+            write!(f, "\n{}", violation)?;
+        }
+        if self.standings.iter().any(|s| s.time.is_some()) {
+            write!(
+                f,
+                "\nTime used: {}",
+                self.standings
+                    .iter()
+                    .map(|s| format!("{} {:.3?}", s.name, s.time.unwrap_or_default()))
+                    .join(", ")
+            )?;
+        }
+        if let Some(seed) = self.seed {
+            write!(f, "\nDealt with seed: {}", seed)?;
+        }
+        Ok(())
+    }
+}
@@ -17,14 +17,14 @@
 //! For each card, the placement procedure is as follows:
 //!
 //! - If its face value is larger than any of the cards on top of one of the four stacks, it is
-//! placed on top of that stack whose top-most card is closest to the case in face value.
+//!   placed on top of that stack whose top-most card is closest to the case in face value.
 //!
 //! - If this stack consists of five cards, the player loses the sum of the "bull" points on the
-//! cards of this stack. The stack is discarded and replaced by the new card.
+//!   cards of this stack. The stack is discarded and replaced by the new card.
 //!
 //! - If the card’s face value is smaller than all of the cards on top of the four stacks, the
-//! player must pick up one of the stacks and start a new one with the card. Again, the player
-//! loses the sum of the bull points on the cards of this stack.
+//!   player must pick up one of the stacks and start a new one with the card. Again, the player
+//!   loses the sum of the bull points on the cards of this stack.
 //!
 //! The round is over when all players have discard all their cards and placed them on the stacks
 //! according to the above procedure.
@@ -54,11 +54,19 @@ extern crate serde_derive;
 
 pub use board::{Board, StackId};
 pub use card::Card;
-pub use configuration::Configuration;
+pub use clock::{Fallback, TimeLimit};
+pub use configuration::{ConfigError, Configuration};
 pub use dealer::Dealer;
-pub use game::Game;
+pub use error::Error;
+pub use game::{Action, ActionError, Game, Status};
+pub use game_result::{GameResult, Standing};
+pub use penalty::{Offense, Penalty, Violation};
 pub use player::Player;
+pub use record::{GameRecord, RecordError, Recorder};
+pub use score_sheet::ScoreSheet;
+pub use snapshot::{GameSnapshot, PlayerSnapshot, SnapshotError};
 pub use stack::Stack;
+pub use turn::{resolve_turn, Outcome};
 
 /// Player trait and implementations.
 pub mod player;
@@ -66,8 +74,28 @@ pub mod player;
 /// Dealer trait and implementations.
 pub mod dealer;
 
+/// Placement rule trait and implementations.
+pub mod placement;
+
+/// Exact solver for the last turns of a round.
+pub mod endgame;
+
+/// End condition trait and implementations.
+pub mod end_condition;
+
+/// Game observer trait and the events it observes.
+pub mod observer;
+
 mod board;
 mod card;
+mod clock;
 mod configuration;
+mod error;
 mod game;
+mod game_result;
+mod penalty;
+mod record;
+mod score_sheet;
+mod snapshot;
 mod stack;
+mod turn;
@@ -0,0 +1,128 @@
+use crate::placement::End;
+use crate::player::Id;
+use crate::{Action, Board, Card, Configuration, GameResult, StackId, Violation};
+use std::sync::mpsc::Sender;
+
+/// Why a player took a stack.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TakeReason {
+    /// The player's card was the one too many for the stack.
+    Full,
+    /// The player's card fit no stack, so the player chose one to take.
+    Low,
+}
+
+/// Something which happened during a game.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GameEvent {
+    /// The game started, with the given players in seat order, and the
+    /// dealer's seed if it has one. Players never see the seed, see
+    /// `PlayerView::history`.
+    GameStarted {
+        configuration: Configuration,
+        players: Vec<(Id, String)>,
+        seed: Option<u64>,
+    },
+    /// A new round, counting from 0, is about to be dealt.
+    RoundStarted { round: usize },
+    /// Every player was dealt their hand.
+    HandsDealt { hands: Vec<(Id, Vec<Card>)> },
+    /// The board was flipped, with a card on each stack.
+    BoardFlipped { board: Board },
+    /// The cards every player played this turn were revealed.
+    CardsRevealed { played: Vec<(Card, Id)> },
+    /// A player broke the rules, and was penalized. The card or stack
+    /// picked for them is given, unless they were ejected, so a replay
+    /// picks the same. Players only see a picked card once it's revealed,
+    /// see `PlayerView::history`.
+    PlayerPenalized {
+        violation: Violation,
+        substitute: Option<Action>,
+    },
+    /// A player's card was placed on a stack.
+    CardPlaced {
+        player: Id,
+        card: Card,
+        stack: StackId,
+        end: End,
+    },
+    /// A player took the cards of a stack.
+    StackTaken {
+        player: Id,
+        stack: StackId,
+        cards: Vec<Card>,
+        reason: TakeReason,
+    },
+    /// A round ended, with the bulls each player took in it.
+    RoundEnded {
+        round: usize,
+        scores: Vec<(Id, u32)>,
+    },
+    /// The game ended.
+    GameEnded { result: GameResult },
+}
+
+/// An observer watches the events of a game, without taking part in it.
+///
+/// Observers are attached to a game by adding them to `Game::observers`.
+/// Closures taking a `&GameEvent` are observers, as are channel `Sender`s,
+/// which receive a copy of every event.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::mpsc::channel;
+/// use take5::Game;
+/// use take5::dealer::StandardDealer;
+/// use take5::observer::{GameEvent, TakeReason};
+/// use take5::player::{AiPlayer, Player};
+///
+/// let players = (0..4).map(|id| {
+///     Box::new(AiPlayer::new(id)) as Box<dyn Player>
+/// }).collect();
+/// let mut game = Game::new(Box::new(StandardDealer::default()), players);
+///
+/// // Print every stack taken because it was full.
+/// game.observers.push(Box::new(|event: &GameEvent| {
+///     if let GameEvent::StackTaken { player, reason: TakeReason::Full, .. } = *event {
+///         println!("player {} took a full stack", player);
+///     }
+/// }));
+///
+/// // Send every event down a channel.
+/// let (sender, receiver) = channel();
+/// game.observers.push(Box::new(sender));
+///
+/// game.run();
+/// let events: Vec<GameEvent> = receiver.try_iter().collect();
+/// assert_eq!(events[1], GameEvent::RoundStarted { round: 0 });
+/// match events.last() {
+///     Some(&GameEvent::GameEnded { .. }) => {}
+///     _ => panic!("the last event should end the game"),
+/// }
+/// ```
+pub trait GameObserver {
+    /// Called with every event of the game, in order.
+    fn notify(&mut self, event: &GameEvent);
+}
+
+impl<F: FnMut(&GameEvent)> GameObserver for F {
+    fn notify(&mut self, event: &GameEvent) {
+        self(event)
+    }
+}
+
+impl GameObserver for Sender<GameEvent> {
+    fn notify(&mut self, event: &GameEvent) {
+        // A closed channel just isn't watching anymore.
+        let _ = self.send(event.clone());
+    }
+}
+
+impl GameObserver for Vec<Box<dyn GameObserver>> {
+    fn notify(&mut self, event: &GameEvent) {
+        for observer in self.iter_mut() {
+            observer.notify(event);
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use crate::player::Id;
+use crate::{Card, StackId};
+use std::fmt;
+
+/// What happens to a player who breaks the rules, for use in a
+/// `Configuration`.
+///
+/// In configuration files this is written as `"substitute"`, `"forfeit"`
+/// or `"eject"`.
+///
+/// # Examples
+///
+/// ```
+/// use take5::{Configuration, Game, Offense, Penalty, Player};
+/// use take5::dealer::StandardDealer;
+/// use take5::player::AiPlayer;
+///
+/// let penalized = |penalty| {
+///     let config = Configuration { penalty, ..Configuration::default() };
+///     let players = (0..3).map(|id| {
+///         Box::new(AiPlayer::new(id)) as Box<dyn Player>
+///     }).collect();
+///     let mut game = Game::with_configuration(config, Box::new(StandardDealer::default()), players);
+///     game.step().unwrap();
+///     game.penalize(0, Offense::Failed("timed out".into())).unwrap();
+///     game
+/// };
+///
+/// // The player's card is picked for them.
+/// let game = penalized(Penalty::Substitute);
+/// assert_eq!(game.hand(0).unwrap().len(), 9);
+///
+/// // The player's card is picked for them, and they finish in last place.
+/// let mut game = penalized(Penalty::Forfeit);
+/// assert_eq!(game.hand(0).unwrap().len(), 9);
+/// let result = game.run();
+/// assert!(result.rounds >= 1);
+/// assert_eq!(result.standings[2].id, 0);
+/// assert!(result.violations[0].disqualifies());
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Penalty {
+    /// A random legal card or stack is picked for the player, and the game
+    /// goes on. When there's nothing to pick, the player is ejected.
+    #[default]
+    Substitute,
+    /// The player is ranked last, but stays in the game, so every other
+    /// player's game goes on as before. Their card or stack is picked as
+    /// for `Substitute`.
+    Forfeit,
+    /// The player is removed from the game, and ranked last. Their hand
+    /// is discarded, and they're never dealt to or asked to play again.
+    Eject,
+}
+
+/// A way a player broke the rules.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Offense {
+    /// The player failed to decide, for the given reason.
+    Failed(String),
+    /// The player played a card which they don't hold.
+    NotInHand(Card),
+    /// The player chose a stack which isn't on the board.
+    NoSuchStack(StackId),
+}
+
+impl fmt::Display for Offense {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            Offense::Failed(ref reason) => write!(f, "failed: {}", reason),
+            Offense::NotInHand(ref card) => write!(f, "played {} which they don't hold", card),
+            Offense::NoSuchStack(stack) => {
+                write!(f, "chose stack {} which isn't on the board", stack)
+            }
+        }
+    }
+}
+
+/// A player breaking the rules during a game, and the penalty they got.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Violation {
+    /// The player's id.
+    pub player: Id,
+    /// The round of the offense, counting from 0.
+    pub round: usize,
+    /// The turn of the offense in the round, counting from 0.
+    pub turn: usize,
+    /// What the player did.
+    pub offense: Offense,
+    /// The penalty the player got.
+    pub penalty: Penalty,
+}
+
+impl Violation {
+    /// Returns true when the player was ranked last for this violation.
+    pub fn disqualifies(&self) -> bool {
+        self.penalty != Penalty::Substitute
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "player {} {} in round {} turn {}, penalty: {:?}",
+            self.player,
+            self.offense,
+            self.round + 1,
+            self.turn + 1,
+            self.penalty
+        )
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{Board, Card, Error, StackId};
+
+pub use self::professional_placement::ProfessionalPlacement;
+pub use self::standard_placement::StandardPlacement;
+
+/// Which end of a stack a card is placed on.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum End {
+    /// On top of the stack, after its highest card.
+    High,
+    /// Underneath the stack, before its lowest card.
+    Low,
+}
+
+/// Where a played card goes on the board.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Position {
+    /// The stack the card is placed on.
+    pub stack: StackId,
+    /// The end of the stack the card is placed on.
+    pub end: End,
+}
+
+/// A placement rule decides which stack a played card goes on.
+///
+/// The dealer uses a placement rule for every card it stacks. If the stack
+/// a card is placed on is full, the player takes the stack first, as
+/// always.
+pub trait PlacementRule {
+    /// Returns the position the given card must be placed at, or `None`
+    /// if the card fits no stack and its player must choose a stack to
+    /// take.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the board isn't one cards can be placed on, such
+    /// as when a stack has no cards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use take5::{Board, Card, Configuration, StackId};
+    /// use take5::placement::{End, PlacementRule, Position, StandardPlacement};
+    ///
+    /// let config = Configuration::default();
+    /// let mut deck = Card::deck(&config, |_| 3);
+    /// let mut board = Board::default();
+    /// for stack in &mut board {
+    ///     stack.push(deck.remove(1), &config);
+    /// }
+    /// let card0 = deck.remove(0);
+    /// let card1 = deck.remove(0);
+    /// assert_eq!(StandardPlacement.place(&board, &card0), Ok(None));
+    /// assert_eq!(
+    ///     StandardPlacement.place(&board, &card1),
+    ///     Ok(Some(Position { stack: StackId::D, end: End::High })),
+    /// );
+    /// ```
+    fn place(&self, board: &Board, card: &Card) -> Result<Option<Position>, Error>;
+}
+
+/// The built in placement rules, for use in a `Configuration`.
+///
+/// In configuration files this is written as `"standard"` or
+/// `"professional"`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Placement {
+    /// The rules of `StandardPlacement`.
+    #[default]
+    Standard,
+    /// The rules of `ProfessionalPlacement`.
+    Professional,
+}
+
+impl PlacementRule for Placement {
+    fn place(&self, board: &Board, card: &Card) -> Result<Option<Position>, Error> {
+        match *self {
+            Placement::Standard => StandardPlacement.place(board, card),
+            Placement::Professional => ProfessionalPlacement.place(board, card),
+        }
+    }
+}
+
+mod professional_placement;
+mod standard_placement;
@@ -0,0 +1,82 @@
+use crate::placement::{End, PlacementRule, Position};
+use crate::{Board, Card, Error};
+
+/// The 6 nimmt! professional rule, cards may also be placed underneath a
+/// stack.
+///
+/// A card goes on whichever end of any stack it is closest to, either on
+/// top of a stack whose highest card is smaller, or underneath a stack
+/// whose lowest card is larger. When both ends are equally close the card
+/// goes on top. Only when a card is larger than the bottom and smaller
+/// than the top of every stack must its player choose a stack to take.
+///
+/// # Examples
+///
+/// ```
+/// use take5::{Board, Card, Configuration, StackId};
+/// use take5::placement::{End, PlacementRule, Position, ProfessionalPlacement};
+///
+/// let config = Configuration::default();
+/// let deck = Card::deck(&config, |_| 3);
+/// let mut board = Board::new(2);
+/// board[StackId::A].push(deck[9].clone(), &config);
+/// board[StackId::A].push(deck[19].clone(), &config);
+/// board[StackId::B].push(deck[49].clone(), &config);
+///
+/// // 5 is closest to the bottom of the first stack.
+/// assert_eq!(
+///     ProfessionalPlacement.place(&board, &deck[4]),
+///     Ok(Some(Position { stack: StackId::A, end: End::Low })),
+/// );
+/// // 40 is closest to the bottom of the second stack.
+/// assert_eq!(
+///     ProfessionalPlacement.place(&board, &deck[39]),
+///     Ok(Some(Position { stack: StackId::B, end: End::Low })),
+/// );
+/// // 60 goes on top of the second stack.
+/// assert_eq!(
+///     ProfessionalPlacement.place(&board, &deck[59]),
+///     Ok(Some(Position { stack: StackId::B, end: End::High })),
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct ProfessionalPlacement;
+
+impl PlacementRule for ProfessionalPlacement {
+    fn place(&self, board: &Board, card: &Card) -> Result<Option<Position>, Error> {
+        let mut best: Option<(u16, Position)> = None;
+        for stack in board.ids() {
+            let (first, last) = match (board[stack].first(), board[stack].last()) {
+                (Some(first), Some(last)) => (first.face(), last.face()),
+                _ => return Err(Error::EmptyStack(stack)),
+            };
+            let candidates = [
+                (
+                    card.face() > last,
+                    card.face().wrapping_sub(last),
+                    End::High,
+                ),
+                (
+                    card.face() < first,
+                    first.wrapping_sub(card.face()),
+                    End::Low,
+                ),
+            ];
+            for &(fits, gap, end) in candidates.iter() {
+                let closer = match best {
+                    Some((best_gap, best_position)) => {
+                        gap < best_gap
+                            || (gap == best_gap
+                                && end == End::High
+                                && best_position.end == End::Low)
+                    }
+                    None => true,
+                };
+                if fits && closer {
+                    best = Some((gap, Position { stack, end }));
+                }
+            }
+        }
+        Ok(best.map(|(_, position)| position))
+    }
+}
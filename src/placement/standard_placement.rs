@@ -0,0 +1,16 @@
+use crate::placement::{End, PlacementRule, Position};
+use crate::{Board, Card, Error};
+
+/// The standard rule, cards are placed on top of the stack whose top card
+/// is the closest smaller card.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct StandardPlacement;
+
+impl PlacementRule for StandardPlacement {
+    fn place(&self, board: &Board, card: &Card) -> Result<Option<Position>, Error> {
+        Ok(board.closest_smaller(card)?.map(|stack| Position {
+            stack,
+            end: End::High,
+        }))
+    }
+}
@@ -1,8 +1,9 @@
-use crate::player::Id;
+use crate::player::{Id, PlayerError, PlayerView};
 use crate::{Board, Card, Player, StackId};
 use std::fmt;
 
-/// A computer player, implemented as a simple AI.
+/// A computer player, implemented as a simple AI. It plays its lowest
+/// card, and takes the stack with the fewest bulls.
 ///
 /// Creating an `AiPlayer` is done with `AiPlayer::new()`.
 #[derive(Debug)]
@@ -10,7 +11,6 @@ pub struct AiPlayer {
     id: Id,
     hand: Vec<Card>,
     pile: Vec<Card>,
-    smallest_stack: StackId,
 }
 
 impl AiPlayer {
@@ -25,10 +25,9 @@ impl AiPlayer {
     /// ```
     pub fn new(id: Id) -> Self {
         AiPlayer {
-            id: id,
+            id,
             hand: Vec::new(),
             pile: Vec::new(),
-            smallest_stack: StackId::A,
         }
     }
 }
@@ -50,29 +49,31 @@ impl Player for AiPlayer {
         self.hand.push(card)
     }
 
-    fn look_at_board(&mut self, board: &Board) {
-        for id in StackId::all().iter() {
-            if board[*id].bulls() < board[self.smallest_stack].bulls() {
-                self.smallest_stack = *id;
-            }
-        }
-    }
-
-    fn look_at_played(&mut self, _played: &[(Card, Id)]) {
-        // Do nothing.
-    }
-
-    fn look_at_taken(&mut self, _taken: (&[Card], Id)) {
-        // Do nothing.
+    fn discard(&mut self, card: &Card) {
+        self.hand.retain(|c| c != card);
     }
 
-    fn play(&mut self) -> Card {
-        self.hand.sort_by(|a, b| b.cmp(a));
-        self.hand.pop().expect("player ran out of cards.")
+    fn play(&mut self, view: &PlayerView) -> Result<Card, PlayerError> {
+        let card = view
+            .hand()
+            .iter()
+            .min()
+            .cloned()
+            .ok_or_else(|| PlayerError::new("player ran out of cards."))?;
+        self.discard(&card);
+        Ok(card)
     }
 
-    fn choose(&self) -> StackId {
-        self.smallest_stack
+    fn choose(
+        &self,
+        _card: &Card,
+        board: &Board,
+        _view: &PlayerView,
+    ) -> Result<StackId, PlayerError> {
+        Ok(board
+            .ids()
+            .min_by_key(|&id| board[id].bulls())
+            .unwrap_or(StackId::A))
     }
 
     fn take(&mut self, cards: Vec<Card>) {
@@ -0,0 +1,27 @@
+use std::time::{Duration, Instant};
+
+/// How much a searching player may simulate for each decision, see
+/// `MonteCarloPlayer`.
+///
+/// A budget of samples makes a seeded player decide the same way every
+/// time. A budget of time lets the player search as far as the machine
+/// allows, so it should be kept under any `TimeLimit` of the game.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Budget {
+    /// Simulate the given number of samples.
+    Samples(usize),
+    /// Simulate for the given time, finishing the sample in progress.
+    /// At least one sample is always simulated.
+    Time(Duration),
+}
+
+impl Budget {
+    /// Returns true when another sample may be simulated, after `done`
+    /// samples simulated since `start`.
+    pub(crate) fn allows(&self, done: usize, start: Instant) -> bool {
+        match *self {
+            Budget::Samples(samples) => done < samples,
+            Budget::Time(time) => done == 0 || start.elapsed() < time,
+        }
+    }
+}
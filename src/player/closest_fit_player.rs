@@ -0,0 +1,126 @@
+use crate::placement::{End, PlacementRule};
+use crate::player::{fewest_bulls, Baseline, Id, PlayerError, PlayerView};
+use crate::{Board, Card, Player, StackId};
+use std::fmt;
+
+/// A baseline player, who plays the card landing closest to the card it's
+/// placed next to, on a stack it won't have to take.
+///
+/// When no card is safe, the player plays their lowest card. They take the
+/// stack with the fewest bulls, breaking ties at random.
+///
+/// # Examples
+///
+/// ```
+/// use take5::{Board, Card, Configuration, Player, StackId};
+/// use take5::player::{ClosestFitPlayer, PlayerView};
+///
+/// let config = Configuration::default();
+/// let deck = Card::deck(&config, |_| 1);
+/// let mut board = Board::new(2);
+/// board[StackId::A].push(deck[9].clone(), &config);
+/// board[StackId::B].push(deck[29].clone(), &config);
+///
+/// // 32 lands 2 above 30, closer than 15 lands above 10.
+/// let mut player = ClosestFitPlayer::seeded(0, 1);
+/// player.draw(deck[14].clone());
+/// player.draw(deck[31].clone());
+/// let view = PlayerView::new(&player, &board, &config);
+/// assert_eq!(player.play(&view), Ok(deck[31].clone()));
+/// ```
+#[derive(Debug)]
+pub struct ClosestFitPlayer {
+    base: Baseline,
+}
+
+impl ClosestFitPlayer {
+    /// Creates a new closest fit player with the given ID, and a random seed.
+    pub fn new(id: Id) -> Self {
+        ClosestFitPlayer {
+            base: Baseline::new(id),
+        }
+    }
+
+    /// Creates a new closest fit player with the given ID, deciding with a
+    /// generator built from the given seed.
+    pub fn seeded(id: Id, seed: u64) -> Self {
+        ClosestFitPlayer {
+            base: Baseline::seeded(id, seed),
+        }
+    }
+
+    /// Returns the seed this player decides with.
+    pub fn seed(&self) -> u64 {
+        self.base.rng.seed()
+    }
+}
+
+impl Player for ClosestFitPlayer {
+    fn id(&self) -> Id {
+        self.base.id
+    }
+
+    fn hand(&self) -> &[Card] {
+        &self.base.hand
+    }
+
+    fn pile(&self) -> &[Card] {
+        &self.base.pile
+    }
+
+    fn draw(&mut self, card: Card) {
+        self.base.draw(card)
+    }
+
+    fn discard(&mut self, card: &Card) {
+        self.base.discard(card)
+    }
+
+    fn play(&mut self, view: &PlayerView) -> Result<Card, PlayerError> {
+        let board = view.board();
+        let config = view.configuration();
+        let closest = view
+            .hand()
+            .iter()
+            .filter_map(|card| {
+                let position = config.placement.place(board, card).ok()??;
+                let stack = &board[position.stack];
+                if stack.full(config) {
+                    return None;
+                }
+                let next = match position.end {
+                    End::High => stack.last(),
+                    End::Low => stack.first(),
+                }?;
+                let gap = (i32::from(card.face()) - i32::from(next.face())).abs();
+                Some((gap, card))
+            })
+            .min_by_key(|&(gap, _)| gap)
+            .map(|(_, card)| card);
+        let card = closest
+            .or_else(|| view.hand().iter().min())
+            .cloned()
+            .ok_or_else(|| PlayerError::new("player ran out of cards."))?;
+        self.discard(&card);
+        Ok(card)
+    }
+
+    fn choose(
+        &self,
+        _card: &Card,
+        board: &Board,
+        _view: &PlayerView,
+    ) -> Result<StackId, PlayerError> {
+        fewest_bulls(board, &mut *self.base.rng.borrow_mut())
+    }
+
+    fn take(&mut self, cards: Vec<Card>) {
+        self.base.take(cards)
+    }
+}
+
+impl fmt::Display for ClosestFitPlayer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "Closest Fit Player {}", self.id())
+    }
+}
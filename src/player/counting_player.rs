@@ -0,0 +1,230 @@
+use crate::placement::{End, PlacementRule};
+use crate::player::{unseen, Id, PlayerError, PlayerView};
+use crate::{Board, Card, Configuration, Player, StackId};
+use std::cmp::Ordering;
+use std::fmt;
+
+/// A computer player who counts cards, playing the card they expect to
+/// cost them the fewest bulls.
+///
+/// The player counts every card they've seen this round from their view:
+/// their hand, the board, and every card revealed and taken. The rest of
+/// the deck, which they're shown before each round, is unseen, and could
+/// be in any opponent's hand. For each card in their hand, the player
+/// estimates the chance that opponents play enough unseen cards in front
+/// of it to make it the one too many for its stack, and plays the card
+/// with the fewest bulls expected.
+///
+/// When they must take a stack, they look ahead to the next turn, taking
+/// the stack which leaves them the best board to play their hand on, for
+/// the fewest bulls in all.
+///
+/// # Examples
+///
+/// ```
+/// use take5::{Board, Card, Configuration, Player, StackId};
+/// use take5::player::{CountingPlayer, PlayerView};
+///
+/// let config = Configuration::default();
+/// let deck = Card::deck(&config, |_| 1);
+/// let mut board = Board::new(2);
+/// for card in &deck[5..10] {
+///     board[StackId::A].push(card.clone(), &config);
+/// }
+/// board[StackId::B].push(deck[49].clone(), &config);
+///
+/// // 11 would be the sixth card of `A`, so 51 is played instead.
+/// let mut player = CountingPlayer::new(0);
+/// player.draw(deck[10].clone());
+/// player.draw(deck[50].clone());
+/// let view = PlayerView::new(&player, &board, &config);
+/// assert_eq!(player.play(&view), Ok(deck[50].clone()));
+/// ```
+#[derive(Debug)]
+pub struct CountingPlayer {
+    id: Id,
+    hand: Vec<Card>,
+    pile: Vec<Card>,
+    deck: Vec<Card>,
+}
+
+impl CountingPlayer {
+    /// Creates a new card counting player with the given ID.
+    pub fn new(id: Id) -> Self {
+        CountingPlayer {
+            id,
+            hand: Vec::new(),
+            pile: Vec::new(),
+            deck: Vec::new(),
+        }
+    }
+
+    /// Returns the fewest bulls the player expects to take by playing any
+    /// card of the given hand on the given board.
+    fn best(&self, hand: &[Card], board: &Board, count: &Count) -> f64 {
+        hand.iter()
+            .map(|card| count.expected(card, board))
+            .min_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+            .unwrap_or(0.0)
+    }
+}
+
+impl Player for CountingPlayer {
+    fn id(&self) -> Id {
+        self.id
+    }
+
+    fn hand(&self) -> &[Card] {
+        &self.hand
+    }
+
+    fn pile(&self) -> &[Card] {
+        &self.pile
+    }
+
+    fn draw(&mut self, card: Card) {
+        self.hand.push(card)
+    }
+
+    fn discard(&mut self, card: &Card) {
+        self.hand.retain(|c| c != card);
+    }
+
+    fn look_at_deck(&mut self, deck: &[Card]) {
+        self.deck = deck.to_vec();
+    }
+
+    fn play(&mut self, view: &PlayerView) -> Result<Card, PlayerError> {
+        let count = Count::new(view, unseen(&self.deck, view));
+        let card = view
+            .hand()
+            .iter()
+            .map(|card| (count.expected(card, view.board()), card))
+            .min_by(|a, b| {
+                a.0.partial_cmp(&b.0)
+                    .unwrap_or(Ordering::Equal)
+                    .then_with(|| a.1.cmp(b.1))
+            })
+            .map(|(_, card)| card.clone())
+            .ok_or_else(|| PlayerError::new("player ran out of cards."))?;
+        self.discard(&card);
+        Ok(card)
+    }
+
+    fn choose(
+        &self,
+        card: &Card,
+        board: &Board,
+        view: &PlayerView,
+    ) -> Result<StackId, PlayerError> {
+        let count = Count::new(view, unseen(&self.deck, view));
+        let hand: Vec<Card> = view.hand().iter().filter(|c| *c != card).cloned().collect();
+        board
+            .ids()
+            .map(|id| {
+                let mut after = board.clone();
+                after[id].give();
+                after[id].push(card.clone(), view.configuration());
+                let bulls = f64::from(board[id].bulls()) + self.best(&hand, &after, &count);
+                (bulls, board[id].bulls(), id)
+            })
+            .min_by(|a, b| {
+                a.0.partial_cmp(&b.0)
+                    .unwrap_or(Ordering::Equal)
+                    .then_with(|| (a.1, a.2).cmp(&(b.1, b.2)))
+            })
+            .map(|(_, _, id)| id)
+            .ok_or_else(|| PlayerError::new("there are no stacks to choose from."))
+    }
+
+    fn take(&mut self, cards: Vec<Card>) {
+        self.pile.extend(cards);
+    }
+}
+
+impl fmt::Display for CountingPlayer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "Counting Player {}", self.id())
+    }
+}
+
+/// The unseen cards, and who could play them this turn.
+struct Count<'a> {
+    unseen: Vec<Card>,
+    opponents: usize,
+    config: &'a Configuration,
+}
+
+impl<'a> Count<'a> {
+    fn new(view: &'a PlayerView, unseen: Vec<Card>) -> Self {
+        Count {
+            unseen,
+            opponents: view.scores().len().saturating_sub(1),
+            config: view.configuration(),
+        }
+    }
+
+    /// Returns the bulls expected to be taken by playing the given card on
+    /// the given board, as each opponent plays a random unseen card.
+    fn expected(&self, card: &Card, board: &Board) -> f64 {
+        let placement = &self.config.placement;
+        let position = match placement.place(board, card) {
+            Ok(Some(position)) => position,
+            // The card fits no stack, so the cheapest one is taken.
+            _ => {
+                return board
+                    .into_iter()
+                    .map(|s| f64::from(s.bulls()))
+                    .fold(f64::INFINITY, f64::min)
+            }
+        };
+        let stack = &board[position.stack];
+        // Unseen cards which land on the same stack, before this one.
+        let ahead: Vec<&Card> = self
+            .unseen
+            .iter()
+            .filter(|u| {
+                let before = match position.end {
+                    End::High => *u < card,
+                    End::Low => *u > card,
+                };
+                before && placement.place(board, u).ok().flatten() == Some(position)
+            })
+            .collect();
+        let mean = if ahead.is_empty() {
+            0.0
+        } else {
+            ahead.iter().map(|c| f64::from(c.bull())).sum::<f64>() / ahead.len() as f64
+        };
+        let q = if self.unseen.is_empty() {
+            0.0
+        } else {
+            ahead.len() as f64 / self.unseen.len() as f64
+        };
+
+        // The card takes the stack when it arrives to a full stack, which
+        // happens when `k`, or `k` plus a multiple of the stack size,
+        // cards are played in front of it.
+        let size = self.config.stack_size;
+        let k = size.saturating_sub(stack.len());
+        let mut expected = 0.0;
+        let mut p = (1.0 - q).powi(self.opponents as i32);
+        for m in 0..=self.opponents {
+            if m >= k && (m - k).is_multiple_of(size) {
+                let bulls = if m == k {
+                    f64::from(stack.bulls()) + m as f64 * mean
+                } else {
+                    size as f64 * mean
+                };
+                expected += p * bulls;
+            }
+            // The binomial probability of `m + 1` cards played in front.
+            if q < 1.0 {
+                p *= (self.opponents - m) as f64 / (m + 1) as f64 * q / (1.0 - q);
+            } else {
+                p = if m + 1 == self.opponents { 1.0 } else { 0.0 };
+            }
+        }
+        expected
+    }
+}
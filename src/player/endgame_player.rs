@@ -0,0 +1,162 @@
+use crate::endgame::Solver;
+use crate::player::{unseen, CountingPlayer, Id, PlayerError, PlayerView, SeededRng};
+use crate::{Board, Card, Player, StackId};
+use rand::{self, Rng};
+use std::fmt;
+
+/// A computer player who solves the end of each round exactly, once they
+/// can.
+///
+/// The end of a round can be solved when the player knows every hand.
+/// This takes both a single opponent and the tactical variant, where
+/// every card the player hasn't seen must be in their opponent's hand.
+/// In a standard game the deck holds cards never dealt, so even with a
+/// single opponent the player never switches over to solving.
+///
+/// Once every hand is known, and the hands are small enough, the player
+/// plays the mixed strategy of an `endgame::Solver`, drawing each card
+/// from their own random number generator, and takes the stacks it finds
+/// best. The solver assumes their opponent plays against them, to give
+/// them the most bulls. Until then they play as a `CountingPlayer`.
+///
+/// # Examples
+///
+/// ```
+/// use take5::{Configuration, Game};
+/// use take5::dealer::StandardDealer;
+/// use take5::player::{CountingPlayer, EndgamePlayer, Player};
+///
+/// let config = Configuration { tactical: true, ..Configuration::default() };
+/// let players = vec![
+///     Box::new(EndgamePlayer::seeded(0, 1)) as Box<dyn Player>,
+///     Box::new(CountingPlayer::new(1)),
+/// ];
+/// let mut game = Game::with_configuration(config, Box::new(StandardDealer::seeded(2)), players);
+/// game.run();
+/// ```
+#[derive(Debug)]
+pub struct EndgamePlayer {
+    counting: CountingPlayer,
+    deck: Vec<Card>,
+    cards: usize,
+    rng: SeededRng,
+}
+
+impl EndgamePlayer {
+    /// Creates a new endgame player with the given ID, and a random seed.
+    pub fn new(id: Id) -> Self {
+        EndgamePlayer::seeded(id, rand::thread_rng().gen())
+    }
+
+    /// Creates a new endgame player with the given ID, playing strategies
+    /// with a generator built from the given seed.
+    pub fn seeded(id: Id, seed: u64) -> Self {
+        EndgamePlayer {
+            counting: CountingPlayer::new(id),
+            deck: Vec::new(),
+            cards: 4,
+            rng: SeededRng::new(seed),
+        }
+    }
+
+    /// Returns the seed this player plays strategies with.
+    pub fn seed(&self) -> u64 {
+        self.rng.seed()
+    }
+
+    /// Returns the most cards the player solves with in hand, which is 4
+    /// by default.
+    pub fn cards(&self) -> usize {
+        self.cards
+    }
+
+    /// Sets the most cards the player solves with in hand. The time taken
+    /// to solve grows very quickly with the cards in hand.
+    pub fn set_cards(&mut self, cards: usize) {
+        self.cards = cards;
+    }
+
+    /// Returns every player's hand, given the player's own, when the
+    /// hands are small enough, and the opponent's is known. That takes a
+    /// single opponent holding every card the player hasn't seen, which
+    /// is only so in the tactical variant.
+    fn hands(&self, hand: Vec<Card>, view: &PlayerView) -> Option<Vec<(Id, Vec<Card>)>> {
+        let mut opponents = view.scores().iter().filter(|s| s.0 != self.id());
+        let opponent = opponents.next()?.0;
+        let unseen = unseen(&self.deck, view);
+        if opponents.next().is_some() || hand.len() > self.cards || unseen.len() != hand.len() {
+            return None;
+        }
+        Some(vec![(self.id(), hand), (opponent, unseen)])
+    }
+}
+
+impl Player for EndgamePlayer {
+    fn id(&self) -> Id {
+        self.counting.id()
+    }
+
+    fn hand(&self) -> &[Card] {
+        self.counting.hand()
+    }
+
+    fn pile(&self) -> &[Card] {
+        self.counting.pile()
+    }
+
+    fn draw(&mut self, card: Card) {
+        self.counting.draw(card)
+    }
+
+    fn discard(&mut self, card: &Card) {
+        self.counting.discard(card)
+    }
+
+    fn look_at_deck(&mut self, deck: &[Card]) {
+        self.deck = deck.to_vec();
+        self.counting.look_at_deck(deck);
+    }
+
+    fn play(&mut self, view: &PlayerView) -> Result<Card, PlayerError> {
+        let hands = match self.hands(view.hand().to_vec(), view) {
+            Some(hands) => hands,
+            None => return self.counting.play(view),
+        };
+        let solution = Solver::new(self.id(), view.configuration())
+            .solve(view.board(), &hands, hands[0].1.len())
+            .map_err(|e| PlayerError::new(format!("could not solve the endgame: {}", e)))?;
+        let card = solution
+            .sample(self.rng.get_mut())
+            .cloned()
+            .ok_or_else(|| PlayerError::new("player ran out of cards."))?;
+        self.discard(&card);
+        Ok(card)
+    }
+
+    fn choose(
+        &self,
+        card: &Card,
+        board: &Board,
+        view: &PlayerView,
+    ) -> Result<StackId, PlayerError> {
+        let hand: Vec<Card> = view.hand().iter().filter(|c| *c != card).cloned().collect();
+        let hands = match self.hands(hand, view) {
+            Some(hands) => hands,
+            None => return self.counting.choose(card, board, view),
+        };
+        let pending = view.pending(card);
+        Solver::new(self.id(), view.configuration())
+            .take(board, card, &pending, &hands, hands[0].1.len())
+            .map_err(|e| PlayerError::new(format!("could not solve the endgame: {}", e)))
+    }
+
+    fn take(&mut self, cards: Vec<Card>) {
+        self.counting.take(cards)
+    }
+}
+
+impl fmt::Display for EndgamePlayer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "Endgame Player {}", self.id())
+    }
+}
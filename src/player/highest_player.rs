@@ -0,0 +1,103 @@
+use crate::player::{fewest_bulls, Baseline, Id, PlayerError, PlayerView};
+use crate::{Board, Card, Player, StackId};
+use std::fmt;
+
+/// A baseline player, who always plays their highest card, and takes the
+/// stack with the fewest bulls.
+///
+/// Ties between stacks with the fewest bulls are broken at random.
+///
+/// # Examples
+///
+/// ```
+/// use take5::{Board, Card, Configuration, Player};
+/// use take5::player::{HighestPlayer, PlayerView};
+///
+/// let config = Configuration::default();
+/// let deck = Card::deck(&config, |_| 1);
+/// let mut player = HighestPlayer::seeded(0, 1);
+/// for card in &deck[..10] {
+///     player.draw(card.clone());
+/// }
+/// let view = PlayerView::new(&player, &Board::default(), &config);
+/// assert_eq!(player.play(&view), Ok(deck[9].clone()));
+/// assert_eq!(player.hand().len(), 9);
+/// ```
+#[derive(Debug)]
+pub struct HighestPlayer {
+    base: Baseline,
+}
+
+impl HighestPlayer {
+    /// Creates a new highest card player with the given ID, and a random seed.
+    pub fn new(id: Id) -> Self {
+        HighestPlayer {
+            base: Baseline::new(id),
+        }
+    }
+
+    /// Creates a new highest card player with the given ID, deciding with a
+    /// generator built from the given seed.
+    pub fn seeded(id: Id, seed: u64) -> Self {
+        HighestPlayer {
+            base: Baseline::seeded(id, seed),
+        }
+    }
+
+    /// Returns the seed this player decides with.
+    pub fn seed(&self) -> u64 {
+        self.base.rng.seed()
+    }
+}
+
+impl Player for HighestPlayer {
+    fn id(&self) -> Id {
+        self.base.id
+    }
+
+    fn hand(&self) -> &[Card] {
+        &self.base.hand
+    }
+
+    fn pile(&self) -> &[Card] {
+        &self.base.pile
+    }
+
+    fn draw(&mut self, card: Card) {
+        self.base.draw(card)
+    }
+
+    fn discard(&mut self, card: &Card) {
+        self.base.discard(card)
+    }
+
+    fn play(&mut self, view: &PlayerView) -> Result<Card, PlayerError> {
+        let card = view
+            .hand()
+            .iter()
+            .max()
+            .cloned()
+            .ok_or_else(|| PlayerError::new("player ran out of cards."))?;
+        self.discard(&card);
+        Ok(card)
+    }
+
+    fn choose(
+        &self,
+        _card: &Card,
+        board: &Board,
+        _view: &PlayerView,
+    ) -> Result<StackId, PlayerError> {
+        fewest_bulls(board, &mut *self.base.rng.borrow_mut())
+    }
+
+    fn take(&mut self, cards: Vec<Card>) {
+        self.base.take(cards)
+    }
+}
+
+impl fmt::Display for HighestPlayer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "Highest Player {}", self.id())
+    }
+}
@@ -0,0 +1,451 @@
+use crate::placement::PlacementRule;
+use crate::player::simulation::{deal, play_out};
+use crate::player::{unseen, Budget, Id, PlayerError, PlayerView, SeededRng};
+use crate::{resolve_turn, Board, Card, Configuration, Error, Outcome, Player, StackId};
+use rand::{self, Rng, StdRng};
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt;
+use std::time::Instant;
+
+/// A computer player who searches a tree of their own decisions, with
+/// information set Monte Carlo tree search.
+///
+/// The tree holds the cards the player could play and the stacks they
+/// could take, over the rest of the round. Every iteration deals the
+/// cards the player hasn't seen to their opponents at random, and walks
+/// down the tree with those hands, picking decisions with the UCB1
+/// formula, until it reaches a decision never tried. From there the round
+/// is played out at random, and the bulls the player took are counted
+/// against every decision on the way. As a decision is only tried when
+/// it's possible with the dealt hands, statistics are shared across every
+/// deal of the hidden cards. The decision tried the most is made.
+///
+/// How many iterations are searched is set by the player's `Budget`, and
+/// how often less promising decisions are tried again by the exploration
+/// constant. The part of the tree below each decision made is kept for
+/// the next decision of the round.
+///
+/// # Examples
+///
+/// Measuring the player against `AiPlayer`, over a few seeded games.
+///
+/// ```
+/// use take5::{Configuration, Game};
+/// use take5::dealer::StandardDealer;
+/// use take5::player::{AiPlayer, Budget, IsmctsPlayer, Player};
+///
+/// let config = Configuration { turn_count: 6, win_score: 30, ..Configuration::default() };
+/// let mut bulls = [0, 0];
+/// for seed in 0..4 {
+///     let players = vec![
+///         Box::new(IsmctsPlayer::seeded(0, Budget::Samples(100), seed)) as Box<dyn Player>,
+///         Box::new(AiPlayer::new(1)),
+///         Box::new(AiPlayer::new(2)),
+///     ];
+///     let dealer = Box::new(StandardDealer::seeded(seed));
+///     let result = Game::with_configuration(config.clone(), dealer, players).run();
+///     for standing in result.standings.iter().filter(|s| s.id < 2) {
+///         bulls[standing.id as usize] += standing.score;
+///     }
+/// }
+/// assert!(bulls[0] < bulls[1]);
+/// ```
+#[derive(Debug)]
+pub struct IsmctsPlayer {
+    id: Id,
+    hand: Vec<Card>,
+    pile: Vec<Card>,
+    deck: Vec<Card>,
+    budget: Budget,
+    exploration: f64,
+    tree: RefCell<Tree>,
+    rng: SeededRng,
+}
+
+impl IsmctsPlayer {
+    /// Creates a new ISMCTS player with the given ID and budget, and a
+    /// random seed.
+    pub fn new(id: Id, budget: Budget) -> Self {
+        IsmctsPlayer::seeded(id, budget, rand::thread_rng().gen())
+    }
+
+    /// Creates a new ISMCTS player with the given ID and budget, searching
+    /// with a generator built from the given seed.
+    pub fn seeded(id: Id, budget: Budget, seed: u64) -> Self {
+        IsmctsPlayer {
+            id,
+            hand: Vec::new(),
+            pile: Vec::new(),
+            deck: Vec::new(),
+            budget,
+            exploration: 0.7,
+            tree: RefCell::new(Tree::default()),
+            rng: SeededRng::new(seed),
+        }
+    }
+
+    /// Returns the budget this player searches with.
+    pub fn budget(&self) -> Budget {
+        self.budget
+    }
+
+    /// Returns the seed this player searches with.
+    pub fn seed(&self) -> u64 {
+        self.rng.seed()
+    }
+
+    /// Returns the exploration constant of the UCB1 formula, which is 0.7
+    /// by default.
+    pub fn exploration(&self) -> f64 {
+        self.exploration
+    }
+
+    /// Sets the exploration constant of the UCB1 formula. Higher values
+    /// try less promising decisions more often.
+    pub fn set_exploration(&mut self, exploration: f64) {
+        self.exploration = exploration;
+    }
+
+    /// Searches from the given position within the budget, returning the
+    /// move tried the most of the given legal moves.
+    fn search(&self, view: &PlayerView, state: State, legal: &[Move]) -> Result<Move, PlayerError> {
+        let unseen = unseen(&self.deck, view);
+        let rng = &mut *self.rng.borrow_mut();
+        let mut tree = self.tree.borrow_mut();
+        let mut root = tree.descend();
+        let search = Search {
+            id: self.id,
+            config: view.configuration(),
+            exploration: self.exploration,
+            scale: (view.configuration().stack_size * view.configuration().bull_range.1 as usize)
+                .max(1) as f64,
+        };
+        let start = Instant::now();
+        let mut done = 0;
+        while self.budget.allows(done, start) {
+            let mut state = state.clone();
+            state.hands.extend(deal(&unseen, view, rng));
+            search
+                .iterate(&mut root, &mut state, rng)
+                .map_err(|e| PlayerError::new(format!("could not search: {}", e)))?;
+            done += 1;
+        }
+        let chosen = legal
+            .iter()
+            .max_by_key(|m| root.child(m).map(|c| c.visits).unwrap_or(0))
+            .cloned()
+            .ok_or_else(|| PlayerError::new("there is nothing to decide between."))?;
+        tree.keep(root, chosen.clone());
+        Ok(chosen)
+    }
+}
+
+impl Player for IsmctsPlayer {
+    fn id(&self) -> Id {
+        self.id
+    }
+
+    fn hand(&self) -> &[Card] {
+        &self.hand
+    }
+
+    fn pile(&self) -> &[Card] {
+        &self.pile
+    }
+
+    fn draw(&mut self, card: Card) {
+        self.hand.push(card)
+    }
+
+    fn discard(&mut self, card: &Card) {
+        self.hand.retain(|c| c != card);
+    }
+
+    fn start_round(&mut self, _round: usize) {
+        *self.tree.get_mut() = Tree::default();
+    }
+
+    fn look_at_deck(&mut self, deck: &[Card]) {
+        self.deck = deck.to_vec();
+    }
+
+    fn play(&mut self, view: &PlayerView) -> Result<Card, PlayerError> {
+        let mut hand = view.hand().to_vec();
+        hand.sort();
+        let legal: Vec<Move> = hand.iter().cloned().map(Move::Play).collect();
+        let state = State {
+            board: view.board().clone(),
+            hands: vec![(self.id, hand)],
+            pending: VecDeque::new(),
+            bulls: 0,
+        };
+        let card = match self.search(view, state, &legal)? {
+            Move::Play(card) => card,
+            Move::Take(_) => unreachable!("only plays are legal"),
+        };
+        self.discard(&card);
+        Ok(card)
+    }
+
+    fn choose(
+        &self,
+        card: &Card,
+        board: &Board,
+        view: &PlayerView,
+    ) -> Result<StackId, PlayerError> {
+        // This card and the rest of this turn's cards are still to place.
+        let mut pending = view.pending(card);
+        pending.insert(0, (card.clone(), self.id));
+        let legal: Vec<Move> = board.ids().map(Move::Take).collect();
+        let state = State {
+            board: board.clone(),
+            hands: vec![(self.id, view.hand().to_vec())],
+            pending: pending.into(),
+            bulls: 0,
+        };
+        match self.search(view, state, &legal)? {
+            Move::Take(stack) => Ok(stack),
+            Move::Play(_) => unreachable!("only stacks are legal"),
+        }
+    }
+
+    fn take(&mut self, cards: Vec<Card>) {
+        self.pile.extend(cards);
+    }
+}
+
+impl fmt::Display for IsmctsPlayer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "ISMCTS Player {}", self.id())
+    }
+}
+
+/// A decision of the searching player.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Move {
+    Play(Card),
+    Take(StackId),
+}
+
+/// A node of the search tree, reached by its move.
+#[derive(Debug)]
+struct Node {
+    action: Option<Move>,
+    visits: u32,
+    available: u32,
+    reward: f64,
+    children: Vec<Node>,
+}
+
+impl Node {
+    fn new(action: Option<Move>) -> Self {
+        Node {
+            action,
+            visits: 0,
+            available: 0,
+            reward: 0.0,
+            children: Vec::new(),
+        }
+    }
+
+    fn child(&self, action: &Move) -> Option<&Node> {
+        self.children
+            .iter()
+            .find(|c| c.action.as_ref() == Some(action))
+    }
+}
+
+/// The search tree of the round, and the last move made from its root.
+#[derive(Debug, Default)]
+struct Tree {
+    root: Option<Node>,
+    made: Option<Move>,
+}
+
+impl Tree {
+    /// Takes the part of the tree below the last move made, or a new
+    /// root when there's none.
+    fn descend(&mut self) -> Node {
+        let made = self.made.take();
+        self.root
+            .take()
+            .and_then(|mut root| {
+                let i = root.children.iter().position(|c| c.action == made)?;
+                Some(root.children.swap_remove(i))
+            })
+            .unwrap_or_else(|| Node::new(None))
+    }
+
+    /// Keeps the given root, with the move made from it.
+    fn keep(&mut self, root: Node, made: Move) {
+        self.root = Some(root);
+        self.made = Some(made);
+    }
+}
+
+/// A position of a round, with every hand dealt.
+#[derive(Clone, Debug)]
+struct State {
+    board: Board,
+    hands: Vec<(Id, Vec<Card>)>,
+    /// The cards of this turn left to place, lowest first.
+    pending: VecDeque<(Card, Id)>,
+    /// The bulls the searching player took since the search started.
+    bulls: u32,
+}
+
+/// The settings of a search.
+struct Search<'a> {
+    id: Id,
+    config: &'a Configuration,
+    exploration: f64,
+    /// The bulls which count as a reward of -1.
+    scale: f64,
+}
+
+impl Search<'_> {
+    /// Runs an iteration of the search from the given node, returning its
+    /// reward.
+    fn iterate(&self, node: &mut Node, state: &mut State, rng: &mut StdRng) -> Result<f64, Error> {
+        let reward = match self.legal(state)? {
+            None => self.reward(state),
+            Some(legal) => {
+                for child in node.children.iter_mut() {
+                    if child.action.as_ref().is_some_and(|a| legal.contains(a)) {
+                        child.available += 1;
+                    }
+                }
+                let untried: Vec<&Move> =
+                    legal.iter().filter(|m| node.child(m).is_none()).collect();
+                if let Some(&action) = rng.choose(&untried) {
+                    let mut child = Node::new(Some(action.clone()));
+                    self.apply(state, action, rng)?;
+                    let reward = self.roll_out(state, rng)?;
+                    child.visits = 1;
+                    child.available = 1;
+                    child.reward = reward;
+                    node.children.push(child);
+                    reward
+                } else {
+                    let child = node
+                        .children
+                        .iter_mut()
+                        .filter(|c| c.action.as_ref().is_some_and(|a| legal.contains(a)))
+                        .max_by(|a, b| {
+                            let a = self.ucb(a);
+                            let b = self.ucb(b);
+                            a.partial_cmp(&b).unwrap_or(std::cmp::Ordering::Equal)
+                        })
+                        .expect("every legal move was tried");
+                    if let Some(ref action) = child.action {
+                        self.apply(state, action, rng)?;
+                    }
+                    self.iterate(child, state, rng)?
+                }
+            }
+        };
+        node.visits += 1;
+        node.reward += reward;
+        Ok(reward)
+    }
+
+    /// Returns the UCB1 value of the given child.
+    fn ucb(&self, child: &Node) -> f64 {
+        let visits = child.visits.max(1) as f64;
+        let available = child.available.max(1) as f64;
+        child.reward / visits + self.exploration * (available.ln() / visits).sqrt()
+    }
+
+    /// Places the pending cards up to the searching player's next
+    /// decision, returning the moves legal there, or `None` once the round
+    /// is over.
+    fn legal(&self, state: &mut State) -> Result<Option<Vec<Move>>, Error> {
+        while let Some((card, id)) = state.pending.pop_front() {
+            if id == self.id && self.config.placement.place(&state.board, &card)?.is_none() {
+                state.pending.push_front((card, id));
+                return Ok(Some(state.board.ids().map(Move::Take).collect()));
+            }
+            self.place(state, (card, id), None)?;
+        }
+        match state.hands.iter().find(|h| h.0 == self.id) {
+            Some((_, hand)) if !hand.is_empty() => {
+                Ok(Some(hand.iter().cloned().map(Move::Play).collect()))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Makes the given move, with every opponent playing a random card
+    /// alongside a play.
+    fn apply(&self, state: &mut State, action: &Move, rng: &mut StdRng) -> Result<(), Error> {
+        match *action {
+            Move::Play(ref card) => {
+                let mut played = Vec::new();
+                for &mut (id, ref mut hand) in state.hands.iter_mut() {
+                    let i = if id == self.id {
+                        hand.iter().position(|c| c == card)
+                    } else if hand.is_empty() {
+                        None
+                    } else {
+                        Some(rng.gen_range(0, hand.len()))
+                    };
+                    if let Some(i) = i {
+                        played.push((hand.swap_remove(i), id));
+                    }
+                }
+                played.sort();
+                state.pending = played.into();
+                Ok(())
+            }
+            Move::Take(stack) => match state.pending.pop_front() {
+                Some(played) => self.place(state, played, Some(stack)),
+                None => Ok(()),
+            },
+        }
+    }
+
+    /// Places a single card, taking the given stack if it fits on none.
+    fn place(
+        &self,
+        state: &mut State,
+        played: (Card, Id),
+        stack: Option<StackId>,
+    ) -> Result<(), Error> {
+        let (after, outcomes) = resolve_turn(
+            &state.board,
+            &[played],
+            &self.config.placement,
+            self.config,
+            |board, _, _| {
+                stack.unwrap_or_else(|| {
+                    board
+                        .ids()
+                        .min_by_key(|&id| board[id].bulls())
+                        .unwrap_or(StackId::A)
+                })
+            },
+        )?;
+        state.board = after;
+        for outcome in outcomes {
+            if let Outcome::Taken { player, cards, .. } = outcome {
+                if player == self.id {
+                    state.bulls += cards.iter().map(|c| u32::from(c.bull())).sum::<u32>();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Plays out the rest of the round at random, returning its reward.
+    fn roll_out(&self, state: &mut State, rng: &mut StdRng) -> Result<f64, Error> {
+        let pending = state.pending.drain(..).collect();
+        let hands = state.hands.clone();
+        state.bulls += play_out(&state.board, pending, hands, self.id, self.config, rng)?;
+        Ok(self.reward(state))
+    }
+
+    fn reward(&self, state: &State) -> f64 {
+        -f64::from(state.bulls) / self.scale
+    }
+}
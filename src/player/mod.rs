@@ -1,17 +1,58 @@
-use crate::{Board, Card, StackId};
-use std::fmt;
+use crate::dealer::seeded_rng;
+use crate::{Board, Card, Configuration, GameResult, StackId};
+use rand::{self, Rng, StdRng};
+use std::cell::{RefCell, RefMut};
+use std::{error, fmt};
 
 pub use self::ai_player::AiPlayer;
+pub use self::budget::Budget;
+pub use self::closest_fit_player::ClosestFitPlayer;
+pub use self::counting_player::CountingPlayer;
+pub use self::endgame_player::EndgamePlayer;
+pub use self::highest_player::HighestPlayer;
+pub use self::ismcts_player::IsmctsPlayer;
+pub use self::monte_carlo_player::MonteCarloPlayer;
+pub use self::random_chooser::RandomChooser;
+pub use self::random_player::RandomPlayer;
+pub use self::replay_player::ReplayPlayer;
+pub use self::view::PlayerView;
 
 /// A unique identifier for a player in the game.
 pub type Id = u8;
 
+/// A player failing to make a decision, for example when a remote player
+/// disconnects.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlayerError {
+    /// Why the player failed.
+    pub reason: String,
+}
+
+impl PlayerError {
+    /// Creates an error for the given reason.
+    pub fn new<S: Into<String>>(reason: S) -> Self {
+        PlayerError {
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for PlayerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "{}", self.reason)
+    }
+}
+
+impl error::Error for PlayerError {}
+
 /// A player draws and plays cards in an attempt to win the game.
 ///
 /// Implementations of `Player` must give some way to create themselves,
 /// this is not spelled out in the interface of a player as this function
-/// might be vary different for different types of players.
-pub trait Player: fmt::Display {
+/// might be vary different for different types of players. Players must
+/// be `Send`, as a game asks them for their decisions on other threads,
+/// see `Game::ask_players`.
+pub trait Player: fmt::Display + Send {
     /// Returns the id of a player.
     ///
     /// # Examples
@@ -30,10 +71,10 @@ pub trait Player: fmt::Display {
     /// # Examples
     ///
     /// ```
-    /// use take5::{Card, Player};
+    /// use take5::{Card, Configuration, Player};
     /// use take5::player::AiPlayer;
     ///
-    /// let mut deck = Card::deck(|_| 3);
+    /// let mut deck = Card::deck(&Configuration::default(), |_| 3);
     /// let mut ai = AiPlayer::new(1);
     ///
     /// // Starts without any cards in it's hand.
@@ -51,10 +92,10 @@ pub trait Player: fmt::Display {
     /// # Examples
     ///
     /// ```
-    /// use take5::{Card, Player};
+    /// use take5::{Card, Configuration, Player};
     /// use take5::player::AiPlayer;
     ///
-    /// let mut deck = Card::deck(|_| 3);
+    /// let mut deck = Card::deck(&Configuration::default(), |_| 3);
     /// let mut ai = AiPlayer::new(1);
     ///
     /// // Starts without any cards in it's pile.
@@ -72,10 +113,10 @@ pub trait Player: fmt::Display {
     /// # Examples
     ///
     /// ```
-    /// use take5::{Card, Player};
+    /// use take5::{Card, Configuration, Player};
     /// use take5::player::AiPlayer;
     ///
-    /// let mut deck = Card::deck(|_| 3);
+    /// let mut deck = Card::deck(&Configuration::default(), |_| 3);
     /// let mut ai = AiPlayer::new(1);
     ///
     /// // Draw 10 cards.
@@ -86,39 +127,149 @@ pub trait Player: fmt::Display {
     /// ```
     fn draw(&mut self, card: Card);
 
-    /// Gives a player the board to look at.
+    /// Removes a card from the player's hand, which the game played for
+    /// them, when they ran out of time or a card was substituted for them.
+    /// A player who keeps the card would go on believing they hold it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use take5::{Card, Configuration, Player};
+    /// use take5::player::AiPlayer;
+    ///
+    /// let deck = Card::deck(&Configuration::default(), |_| 3);
+    /// let mut ai = AiPlayer::new(1);
+    /// ai.draw(deck[0].clone());
+    /// ai.draw(deck[1].clone());
+    ///
+    /// ai.discard(&deck[0]);
+    /// assert_eq!(ai.hand(), &deck[1..2]);
+    /// ```
+    fn discard(&mut self, card: &Card);
+
+    /// Tells a player the game is starting, with the ids of every player
+    /// in seat order, their own included. By default this does nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use take5::Player;
+    /// use take5::player::AiPlayer;
+    ///
+    /// let mut ai = AiPlayer::new(1);
+    ///
+    /// // Sit between players 0 and 2.
+    /// ai.start_game(&[0, 1, 2]);
+    /// ```
+    fn start_game(&mut self, _seats: &[Id]) {}
+
+    /// Tells a player the given round, counting from 0, is starting. This
+    /// is called before the round is dealt. By default this does nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use take5::Player;
+    /// use take5::player::AiPlayer;
+    ///
+    /// let mut ai = AiPlayer::new(1);
+    /// ai.start_round(0);
+    /// ```
+    fn start_round(&mut self, _round: usize) {}
+
+    /// Tells a player the given round ended, with the bulls each player
+    /// took in it. By default this does nothing.
     ///
     /// # Examples
     ///
     /// ```
-    /// use take5::{Card, Board, Player, StackId};
+    /// use take5::Player;
     /// use take5::player::AiPlayer;
     ///
-    /// let mut deck = Card::deck(|_| 3);
+    /// let mut ai = AiPlayer::new(1);
+    /// ai.end_round(0, &[(0, 12), (1, 3)]);
+    /// ```
+    fn end_round(&mut self, _round: usize, _scores: &[(Id, u32)]) {}
+
+    /// Tells a player the game is over, with its result. By default this
+    /// does nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use take5::{Game, Player};
+    /// use take5::dealer::StandardDealer;
+    /// use take5::player::AiPlayer;
+    ///
+    /// let players = (0..3).map(|id| {
+    ///     Box::new(AiPlayer::new(id)) as Box<dyn Player>
+    /// }).collect();
+    /// let mut game = Game::new(Box::new(StandardDealer::default()), players);
+    /// let result = game.run();
+    ///
+    /// // Tell a player about the game after the fact.
+    /// let mut ai = AiPlayer::new(4);
+    /// ai.end_game(&result);
+    /// ```
+    fn end_game(&mut self, _result: &GameResult) {}
+
+    /// Gives a player every card in the deck to look at, before the round
+    /// is dealt. In the tactical variant every one of these cards is dealt
+    /// to a player or flipped onto the board, so all outstanding cards are
+    /// known. By default this does nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use take5::{Card, Configuration, Player};
+    /// use take5::player::AiPlayer;
+    ///
+    /// let config = Configuration { tactical: true, ..Configuration::default() };
+    /// let deck = Card::deck(&config.for_players(4).unwrap(), |_| 3);
+    /// let mut ai = AiPlayer::new(1);
+    ///
+    /// // Look at the cards in play.
+    /// ai.look_at_deck(&deck);
+    /// ```
+    fn look_at_deck(&mut self, _deck: &[Card]) {}
+
+    /// Gives a player the board to look at. By default this does nothing,
+    /// as the board is also in the view given to `play` and `choose`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use take5::{Card, Board, Configuration, Player, StackId};
+    /// use take5::player::AiPlayer;
+    ///
+    /// let config = Configuration::default();
+    /// let mut deck = Card::deck(&config, |_| 3);
     /// let mut ai = AiPlayer::new(1);
     ///
     /// // Setup a game.
     /// let mut board = Board::default();
     /// for stack in &mut board {
-    ///     stack.push(deck.pop().unwrap());
+    ///     stack.push(deck.pop().unwrap(), &config);
     /// }
     ///
     /// // Look at the board.
     /// ai.look_at_board(&board);
     /// ```
-    fn look_at_board(&mut self, board: &Board);
+    fn look_at_board(&mut self, _board: &Board) {}
 
     /// Gives a player the played cards to look at. The played cards are
     /// given as pairs of the card, and the `Id` of the player who played
-    /// the card.
+    /// the card. By default this does nothing, as played cards are also in
+    /// the view's history.
     ///
     /// # Examples
     ///
     /// ```
-    /// use take5::{Card, Board, Player, StackId};
+    /// use take5::{Card, Board, Configuration, Player, StackId};
     /// use take5::player::AiPlayer;
     ///
-    /// let mut deck = Card::deck(|_| 3);
+    /// let config = Configuration::default();
+    /// let mut deck = Card::deck(&config, |_| 3);
     /// let mut ai = AiPlayer::new(1);
     ///
     /// // Setup a game.
@@ -130,19 +281,21 @@ pub trait Player: fmt::Display {
     /// // Look at the played cards.
     /// ai.look_at_played(&played);
     /// ```
-    fn look_at_played(&mut self, played: &[(Card, Id)]);
+    fn look_at_played(&mut self, _played: &[(Card, Id)]) {}
 
     /// Gives a player the taken cards to look at. The taken cards are
     /// given as a collection of cards and the `Id` of the player taking
-    /// them.
+    /// them. By default this does nothing, as taken cards are also in the
+    /// view's history.
     ///
     /// # Examples
     ///
     /// ```
-    /// use take5::{Card, Board, Player, StackId};
+    /// use take5::{Card, Board, Configuration, Player, StackId};
     /// use take5::player::AiPlayer;
     ///
-    /// let mut deck = Card::deck(|_| 3);
+    /// let config = Configuration::default();
+    /// let mut deck = Card::deck(&config, |_| 3);
     /// let mut ai = AiPlayer::new(1);
     ///
     /// // Setup a game.
@@ -154,28 +307,29 @@ pub trait Player: fmt::Display {
     /// // Look at taken cards.
     /// ai.look_at_taken((&taken, 1));
     /// ```
-    fn look_at_taken(&mut self, taken: (&[Card], Id));
+    fn look_at_taken(&mut self, _taken: (&[Card], Id)) {}
 
-    /// Returns a card the player wishes to play.
+    /// Returns a card the player wishes to play, removing it from their
+    /// hand. The view holds everything the player may know about the game,
+    /// see `PlayerView`.
     ///
-    /// # Panics
+    /// The game checks the card against the hand it dealt the player, and
+    /// penalizes the player if they don't hold it, see `Penalty`.
     ///
-    /// Player implementations are allowed to panic if there are no cards
-    /// in any stack of the board. The board can be expected to have at least
-    /// one card in each stack. This is the responsibility of the dealer
-    /// to maintain.
+    /// # Errors
     ///
-    /// Player implementations may also panic if asked to play a card when
-    /// they are out of cards. The dealer should never ask this of a player
-    /// since the number of turns in a round is fixed to 10.
+    /// Returns `Err` if the player fails to decide on a card, which the
+    /// game penalizes as well. Players may also fail if asked to play a
+    /// card when they are out of cards, which the game never asks.
     ///
     /// # Examples
     ///
     /// ```
-    /// use take5::{Card, Board, Player, StackId};
-    /// use take5::player::AiPlayer;
+    /// use take5::{Card, Board, Configuration, Player, StackId};
+    /// use take5::player::{AiPlayer, PlayerView};
     ///
-    /// let mut deck = Card::deck(|_| 3);
+    /// let config = Configuration::default();
+    /// let mut deck = Card::deck(&config, |_| 3);
     /// let mut ai = AiPlayer::new(1);
     ///
     /// // Draw a card.
@@ -183,49 +337,56 @@ pub trait Player: fmt::Display {
     /// let face = card.face();
     /// ai.draw(card);
     /// // With only one card, the player must play it.
-    /// assert_eq!(ai.play().face(), face);
+    /// let view = PlayerView::new(&ai, &Board::default(), &config);
+    /// assert_eq!(ai.play(&view).unwrap().face(), face);
     /// ```
-    fn play(&mut self) -> Card;
+    fn play(&mut self, view: &PlayerView) -> Result<Card, PlayerError>;
 
-    /// If a played card is lower than all cards on the board, return
-    /// the `StackId` of the stack you wish to take.
+    /// If the given played card is lower than all cards on the given
+    /// board, return the `StackId` of the stack you wish to take.
     ///
-    /// # Panics
+    /// The board can be expected to have at least one card in each stack.
+    /// This is the responsibility of the dealer to maintain.
     ///
-    /// Player implementations are allowed to panic if there are no cards
-    /// in any stack of the board. The board can be expected to have at least
-    /// one card in each stack. This is the responsibility of the dealer
-    /// to maintain.
+    /// # Errors
+    ///
+    /// Returns `Err` if the player fails to decide on a stack. Like
+    /// choosing a stack which isn't on the board, this is penalized by the
+    /// game.
     ///
     /// # Examples
     ///
     /// ```
-    /// use take5::{Card, Board, Player, StackId};
-    /// use take5::player::AiPlayer;
+    /// use take5::{Card, Board, Configuration, Player, StackId};
+    /// use take5::player::{AiPlayer, PlayerView};
     ///
-    /// let mut deck = Card::deck(|_| 3);
+    /// let config = Configuration::default();
+    /// let mut deck = Card::deck(&config, |_| 3);
     /// let mut ai = AiPlayer::new(1);
     ///
     /// // Setup a board.
     /// let mut board = Board::default();
     /// for stack in &mut board {
-    ///     stack.push(deck.pop().unwrap());
+    ///     stack.push(deck.pop().unwrap(), &config);
     /// }
     ///
-    /// // Print the stack this player chose.
-    /// println!("{}", board[ai.choose()]);
+    /// // Print the stack this player chose for a card lower than them all.
+    /// let card = deck.remove(0);
+    /// let view = PlayerView::new(&ai, &board, &config);
+    /// println!("{}", board[ai.choose(&card, &board, &view).unwrap()]);
     /// ```
-    fn choose(&self) -> StackId;
+    fn choose(&self, card: &Card, board: &Board, view: &PlayerView)
+        -> Result<StackId, PlayerError>;
 
     /// Take a set of cards for the player to add to their pile.
     ///
     /// # Examples
     ///
     /// ```
-    /// use take5::{Card, Player};
+    /// use take5::{Card, Configuration, Player};
     /// use take5::player::AiPlayer;
     ///
-    /// let mut deck = Card::deck(|_| 3);
+    /// let mut deck = Card::deck(&Configuration::default(), |_| 3);
     /// let mut ai = AiPlayer::new(1);
     ///
     /// // Takes five cards.
@@ -243,10 +404,10 @@ pub trait Player: fmt::Display {
     /// # Examples
     ///
     /// ```
-    /// use take5::{Card, Player};
+    /// use take5::{Card, Configuration, Player};
     /// use take5::player::AiPlayer;
     ///
-    /// let mut deck = Card::deck(|_| 3);
+    /// let mut deck = Card::deck(&Configuration::default(), |_| 3);
     /// let mut ai = AiPlayer::new(1);
     ///
     /// // Takes five cards.
@@ -259,4 +420,141 @@ pub trait Player: fmt::Display {
     }
 }
 
+/// Returns the cards of the given deck, shown to the player before the
+/// round, which the player hasn't seen. Out of a game the deck was never
+/// shown, so every card of the configured deck is counted, with the
+/// fewest bulls a card may have.
+fn unseen(deck: &[Card], view: &PlayerView) -> Vec<Card> {
+    let seen = view.seen();
+    let deck = if deck.is_empty() {
+        let config = Configuration {
+            deck_size: view.deck as u16,
+            ..view.configuration().clone()
+        };
+        Card::deck(&config, |_| config.bull_range.0)
+    } else {
+        deck.to_vec()
+    };
+    deck.into_iter()
+        .filter(|card| !seen.contains(&card))
+        .collect()
+}
+
+/// A player's own random number generator, and the seed it was built
+/// from.
+///
+/// Players draw every random decision from their own generator, never the
+/// thread's, so a player built from a seed decides the same way every time
+/// they see the same game, and games between them can be replayed exactly.
+/// Players built without a seed pick one at random.
+struct SeededRng {
+    rng: RefCell<StdRng>,
+    seed: u64,
+}
+
+impl SeededRng {
+    fn new(seed: u64) -> Self {
+        SeededRng {
+            rng: RefCell::new(seeded_rng(seed)),
+            seed,
+        }
+    }
+
+    fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Borrows the generator, for decisions made through `&self`.
+    fn borrow_mut(&self) -> RefMut<'_, StdRng> {
+        self.rng.borrow_mut()
+    }
+
+    fn get_mut(&mut self) -> &mut StdRng {
+        self.rng.get_mut()
+    }
+}
+
+impl fmt::Debug for SeededRng {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.debug_struct("SeededRng")
+            .field("seed", &self.seed)
+            .finish()
+    }
+}
+
+/// The hand, pile, and generator of a baseline player, who differs from
+/// the other baselines only in how they play and choose.
+#[derive(Debug)]
+struct Baseline {
+    id: Id,
+    hand: Vec<Card>,
+    pile: Vec<Card>,
+    rng: SeededRng,
+}
+
+impl Baseline {
+    /// Creates a baseline with the given ID, and a random seed.
+    fn new(id: Id) -> Self {
+        Baseline::seeded(id, rand::thread_rng().gen())
+    }
+
+    /// Creates a baseline with the given ID, deciding with a generator
+    /// built from the given seed.
+    fn seeded(id: Id, seed: u64) -> Self {
+        Baseline {
+            id,
+            hand: Vec::new(),
+            pile: Vec::new(),
+            rng: SeededRng::new(seed),
+        }
+    }
+
+    fn draw(&mut self, card: Card) {
+        self.hand.push(card)
+    }
+
+    fn discard(&mut self, card: &Card) {
+        self.hand.retain(|c| c != card);
+    }
+
+    fn take(&mut self, cards: Vec<Card>) {
+        self.pile.extend(cards);
+    }
+}
+
+/// Returns a random stack of the given board.
+fn random_stack<R: Rng>(board: &Board, rng: &mut R) -> Result<StackId, PlayerError> {
+    if board.is_empty() {
+        return Err(PlayerError::new("there are no stacks to choose from."));
+    }
+    Ok(StackId::new(rng.gen_range(0, board.len())))
+}
+
+/// Returns the stack of the given board with the fewest bulls, breaking
+/// ties at random.
+fn fewest_bulls<R: Rng>(board: &Board, rng: &mut R) -> Result<StackId, PlayerError> {
+    let fewest = board
+        .ids()
+        .map(|id| board[id].bulls())
+        .min()
+        .ok_or_else(|| PlayerError::new("there are no stacks to choose from."))?;
+    let ids: Vec<StackId> = board
+        .ids()
+        .filter(|&id| board[id].bulls() == fewest)
+        .collect();
+    Ok(ids[rng.gen_range(0, ids.len())])
+}
+
 mod ai_player;
+mod budget;
+mod closest_fit_player;
+mod counting_player;
+mod endgame_player;
+mod highest_player;
+mod ismcts_player;
+mod monte_carlo_player;
+mod random_chooser;
+mod random_player;
+mod replay_player;
+mod simulation;
+mod view;
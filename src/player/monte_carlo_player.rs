@@ -0,0 +1,192 @@
+use crate::player::simulation::{deal, play_out};
+use crate::player::{unseen, Budget, Id, PlayerError, PlayerView, SeededRng};
+use crate::{Board, Card, Error, Player, StackId};
+use rand::{self, Rng, StdRng};
+use std::fmt;
+use std::time::Instant;
+
+/// A computer player who searches by sampling what their opponents could
+/// hold.
+///
+/// For every sample the player deals the cards they haven't seen to their
+/// opponents at random, as many as each opponent holds. With every
+/// opponent's hand fixed by the sample, each card the player could play
+/// is tried, playing out the rest of the round with every player playing
+/// random cards, and taking the stack with the fewest bulls. The card
+/// taking the fewest bulls over all samples is played. Stacks are chosen
+/// the same way, trying each stack in turn.
+///
+/// How many samples are played out is set by the player's `Budget`.
+/// Samples are drawn from the player's own random number generator, so a
+/// player built from a seed with a budget of samples decides the same way
+/// every time it sees the same game.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use take5::{Configuration, Game};
+/// use take5::dealer::StandardDealer;
+/// use take5::player::{AiPlayer, Budget, MonteCarloPlayer, Player};
+///
+/// let config = Configuration { turn_count: 5, win_score: 20, ..Configuration::default() };
+/// let players = vec![
+///     Box::new(MonteCarloPlayer::seeded(0, Budget::Samples(10), 1)) as Box<dyn Player>,
+///     Box::new(MonteCarloPlayer::new(1, Budget::Time(Duration::from_millis(5)))),
+///     Box::new(AiPlayer::new(2)),
+/// ];
+/// let mut game = Game::with_configuration(config, Box::new(StandardDealer::seeded(3)), players);
+/// game.run();
+/// ```
+#[derive(Debug)]
+pub struct MonteCarloPlayer {
+    id: Id,
+    hand: Vec<Card>,
+    pile: Vec<Card>,
+    deck: Vec<Card>,
+    budget: Budget,
+    rng: SeededRng,
+}
+
+impl MonteCarloPlayer {
+    /// Creates a new Monte Carlo player with the given ID and budget, and
+    /// a random seed.
+    pub fn new(id: Id, budget: Budget) -> Self {
+        MonteCarloPlayer::seeded(id, budget, rand::thread_rng().gen())
+    }
+
+    /// Creates a new Monte Carlo player with the given ID and budget,
+    /// sampling with a generator built from the given seed.
+    pub fn seeded(id: Id, budget: Budget, seed: u64) -> Self {
+        MonteCarloPlayer {
+            id,
+            hand: Vec::new(),
+            pile: Vec::new(),
+            deck: Vec::new(),
+            budget,
+            rng: SeededRng::new(seed),
+        }
+    }
+
+    /// Returns the budget this player searches with.
+    pub fn budget(&self) -> Budget {
+        self.budget
+    }
+
+    /// Returns the seed this player samples with.
+    pub fn seed(&self) -> u64 {
+        self.rng.seed()
+    }
+
+    /// Samples the opponents' hands within the budget, returning the
+    /// index of the option taking the fewest bulls in total. Each option
+    /// is played out by `bulls`, given the sampled hands and a generator.
+    fn search<F>(
+        &self,
+        view: &PlayerView,
+        options: usize,
+        mut bulls: F,
+    ) -> Result<usize, PlayerError>
+    where
+        F: FnMut(usize, Vec<(Id, Vec<Card>)>, &mut StdRng) -> Result<u32, Error>,
+    {
+        let unseen = unseen(&self.deck, view);
+        let rng = &mut *self.rng.borrow_mut();
+        let mut totals = vec![0u64; options];
+        let start = Instant::now();
+        let mut done = 0;
+        while self.budget.allows(done, start) {
+            let hands = deal(&unseen, view, rng);
+            for (i, total) in totals.iter_mut().enumerate() {
+                let taken = bulls(i, hands.clone(), rng)
+                    .map_err(|e| PlayerError::new(format!("could not play out a sample: {}", e)))?;
+                *total += u64::from(taken);
+            }
+            done += 1;
+        }
+        (0..options)
+            .min_by_key(|&i| totals[i])
+            .ok_or_else(|| PlayerError::new("there is nothing to decide between."))
+    }
+}
+
+impl Player for MonteCarloPlayer {
+    fn id(&self) -> Id {
+        self.id
+    }
+
+    fn hand(&self) -> &[Card] {
+        &self.hand
+    }
+
+    fn pile(&self) -> &[Card] {
+        &self.pile
+    }
+
+    fn draw(&mut self, card: Card) {
+        self.hand.push(card)
+    }
+
+    fn discard(&mut self, card: &Card) {
+        self.hand.retain(|c| c != card);
+    }
+
+    fn look_at_deck(&mut self, deck: &[Card]) {
+        self.deck = deck.to_vec();
+    }
+
+    fn play(&mut self, view: &PlayerView) -> Result<Card, PlayerError> {
+        let mut hand = view.hand().to_vec();
+        hand.sort();
+        let id = self.id;
+        let board = view.board();
+        let config = view.configuration();
+        let i = self.search(view, hand.len(), |i, mut hands, rng| {
+            let mut rest = hand.clone();
+            let card = rest.remove(i);
+            let mut played = vec![(card, id)];
+            for &mut (other, ref mut held) in hands.iter_mut() {
+                if !held.is_empty() {
+                    let j = rng.gen_range(0, held.len());
+                    played.push((held.swap_remove(j), other));
+                }
+            }
+            hands.push((id, rest));
+            play_out(board, played, hands, id, config, rng)
+        })?;
+        let card = hand.swap_remove(i);
+        self.discard(&card);
+        Ok(card)
+    }
+
+    fn choose(
+        &self,
+        card: &Card,
+        board: &Board,
+        view: &PlayerView,
+    ) -> Result<StackId, PlayerError> {
+        let pending = view.pending(card);
+        let ids: Vec<StackId> = board.ids().collect();
+        let config = view.configuration();
+        let hand: Vec<Card> = view.hand().iter().filter(|c| *c != card).cloned().collect();
+        let i = self.search(view, ids.len(), |i, mut hands, rng| {
+            let mut after = board.clone();
+            let taken = after[ids[i]].give();
+            after[ids[i]].try_push(card.clone(), config)?;
+            hands.push((self.id, hand.clone()));
+            let bulls = play_out(&after, pending.clone(), hands, self.id, config, rng)?;
+            Ok(taken.iter().map(|c| u32::from(c.bull())).sum::<u32>() + bulls)
+        })?;
+        Ok(ids[i])
+    }
+
+    fn take(&mut self, cards: Vec<Card>) {
+        self.pile.extend(cards);
+    }
+}
+
+impl fmt::Display for MonteCarloPlayer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "Monte Carlo Player {}", self.id())
+    }
+}
@@ -0,0 +1,107 @@
+use crate::player::{random_stack, Baseline, Id, PlayerError, PlayerView};
+use crate::{Board, Card, Player, StackId};
+use std::fmt;
+
+/// A baseline player, who plays their lowest card like `AiPlayer`, but
+/// takes a random stack.
+///
+/// Compared against an `AiPlayer`, this measures what choosing well is
+/// worth.
+///
+/// # Examples
+///
+/// ```
+/// use take5::{Board, Card, Configuration, Player};
+/// use take5::player::{PlayerView, RandomChooser};
+///
+/// let config = Configuration::default();
+/// let mut deck = Card::deck(&config, |_| 1);
+/// let mut board = Board::default();
+/// for stack in &mut board {
+///     stack.push(deck.pop().unwrap(), &config);
+/// }
+/// let choices = |seed| {
+///     let player = RandomChooser::seeded(0, seed);
+///     let view = PlayerView::new(&player, &board, &config);
+///     (0..10).map(|_| player.choose(&deck[0], &board, &view).unwrap()).collect::<Vec<_>>()
+/// };
+/// assert_eq!(choices(3), choices(3));
+/// ```
+#[derive(Debug)]
+pub struct RandomChooser {
+    base: Baseline,
+}
+
+impl RandomChooser {
+    /// Creates a new random chooser with the given ID, and a random seed.
+    pub fn new(id: Id) -> Self {
+        RandomChooser {
+            base: Baseline::new(id),
+        }
+    }
+
+    /// Creates a new random chooser with the given ID, deciding with a
+    /// generator built from the given seed.
+    pub fn seeded(id: Id, seed: u64) -> Self {
+        RandomChooser {
+            base: Baseline::seeded(id, seed),
+        }
+    }
+
+    /// Returns the seed this player decides with.
+    pub fn seed(&self) -> u64 {
+        self.base.rng.seed()
+    }
+}
+
+impl Player for RandomChooser {
+    fn id(&self) -> Id {
+        self.base.id
+    }
+
+    fn hand(&self) -> &[Card] {
+        &self.base.hand
+    }
+
+    fn pile(&self) -> &[Card] {
+        &self.base.pile
+    }
+
+    fn draw(&mut self, card: Card) {
+        self.base.draw(card)
+    }
+
+    fn discard(&mut self, card: &Card) {
+        self.base.discard(card)
+    }
+
+    fn play(&mut self, view: &PlayerView) -> Result<Card, PlayerError> {
+        let card = view
+            .hand()
+            .iter()
+            .min()
+            .cloned()
+            .ok_or_else(|| PlayerError::new("player ran out of cards."))?;
+        self.discard(&card);
+        Ok(card)
+    }
+
+    fn choose(
+        &self,
+        _card: &Card,
+        board: &Board,
+        _view: &PlayerView,
+    ) -> Result<StackId, PlayerError> {
+        random_stack(board, &mut *self.base.rng.borrow_mut())
+    }
+
+    fn take(&mut self, cards: Vec<Card>) {
+        self.base.take(cards)
+    }
+}
+
+impl fmt::Display for RandomChooser {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "Random Chooser {}", self.id())
+    }
+}
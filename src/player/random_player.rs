@@ -0,0 +1,101 @@
+use crate::player::{random_stack, Baseline, Id, PlayerError, PlayerView};
+use crate::{Board, Card, Player, StackId};
+use rand::Rng;
+use std::fmt;
+
+/// A baseline player, who plays a random card, and takes a random stack.
+///
+/// # Examples
+///
+/// ```
+/// use take5::Game;
+/// use take5::dealer::StandardDealer;
+/// use take5::player::{Player, RandomPlayer};
+///
+/// let play = |seed| {
+///     let players = (0..4).map(|id| {
+///         Box::new(RandomPlayer::seeded(id, seed + id as u64)) as Box<dyn Player>
+///     }).collect();
+///     Game::new(Box::new(StandardDealer::seeded(seed)), players).run()
+/// };
+/// assert_eq!(play(5), play(5));
+/// ```
+#[derive(Debug)]
+pub struct RandomPlayer {
+    base: Baseline,
+}
+
+impl RandomPlayer {
+    /// Creates a new random player with the given ID, and a random seed.
+    pub fn new(id: Id) -> Self {
+        RandomPlayer {
+            base: Baseline::new(id),
+        }
+    }
+
+    /// Creates a new random player with the given ID, deciding with a
+    /// generator built from the given seed.
+    pub fn seeded(id: Id, seed: u64) -> Self {
+        RandomPlayer {
+            base: Baseline::seeded(id, seed),
+        }
+    }
+
+    /// Returns the seed this player decides with.
+    pub fn seed(&self) -> u64 {
+        self.base.rng.seed()
+    }
+}
+
+impl Player for RandomPlayer {
+    fn id(&self) -> Id {
+        self.base.id
+    }
+
+    fn hand(&self) -> &[Card] {
+        &self.base.hand
+    }
+
+    fn pile(&self) -> &[Card] {
+        &self.base.pile
+    }
+
+    fn draw(&mut self, card: Card) {
+        self.base.draw(card)
+    }
+
+    fn discard(&mut self, card: &Card) {
+        self.base.discard(card)
+    }
+
+    fn play(&mut self, view: &PlayerView) -> Result<Card, PlayerError> {
+        let card = self
+            .base
+            .rng
+            .get_mut()
+            .choose(view.hand())
+            .cloned()
+            .ok_or_else(|| PlayerError::new("player ran out of cards."))?;
+        self.discard(&card);
+        Ok(card)
+    }
+
+    fn choose(
+        &self,
+        _card: &Card,
+        board: &Board,
+        _view: &PlayerView,
+    ) -> Result<StackId, PlayerError> {
+        random_stack(board, &mut *self.base.rng.borrow_mut())
+    }
+
+    fn take(&mut self, cards: Vec<Card>) {
+        self.base.take(cards)
+    }
+}
+
+impl fmt::Display for RandomPlayer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "Random Player {}", self.id())
+    }
+}
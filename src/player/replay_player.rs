@@ -0,0 +1,159 @@
+use crate::observer::{GameEvent, TakeReason};
+use crate::player::{Id, PlayerError, PlayerView};
+use crate::{Action, Board, Card, GameRecord, Offense, Player, StackId};
+use std::cell::Cell;
+use std::collections::VecDeque;
+use std::fmt;
+
+/// A scripted player, making the plays and choices of a player in a
+/// recorded game.
+///
+/// Where the recorded player broke the rules, the player breaks them the
+/// same way, so the replay penalizes them as the game did. Once the replay
+/// differs from the record and no recorded decision is left, the player plays its lowest card, and chooses stack `A`, so the
+/// replay can still finish. See `GameRecord::replay`.
+#[derive(Debug, Clone)]
+pub struct ReplayPlayer {
+    id: Id,
+    name: String,
+    hand: Vec<Card>,
+    pile: Vec<Card>,
+    plays: VecDeque<Result<Card, Offense>>,
+    choices: Vec<Result<StackId, Offense>>,
+    chosen: Cell<usize>,
+}
+
+impl ReplayPlayer {
+    /// Creates a player replaying the player with the given ID in the
+    /// given record.
+    pub fn new(record: &GameRecord, id: Id) -> Self {
+        let mut plays = VecDeque::new();
+        let mut choices = Vec::new();
+        // Turns revealed this round, to tell penalized plays from choices.
+        let mut revealed = 0;
+        // The card or stack picked for the player was never their decision.
+        let mut substituted = None;
+        for event in record.events() {
+            match *event {
+                GameEvent::RoundStarted { .. } => revealed = 0,
+                GameEvent::PlayerPenalized {
+                    ref violation,
+                    ref substitute,
+                } if violation.player == id => {
+                    let offense = violation.offense.clone();
+                    if violation.turn == revealed {
+                        plays.push_back(Err(offense));
+                    } else {
+                        choices.push(Err(offense));
+                    }
+                    substituted = substitute.clone();
+                }
+                GameEvent::CardsRevealed { ref played } => {
+                    revealed += 1;
+                    for (card, _) in played.iter().filter(|p| p.1 == id) {
+                        match substituted {
+                            Some(Action::Play(..)) => substituted = None,
+                            _ => plays.push_back(Ok(card.clone())),
+                        }
+                    }
+                }
+                GameEvent::StackTaken {
+                    player,
+                    stack,
+                    reason: TakeReason::Low,
+                    ..
+                } if player == id => match substituted {
+                    Some(Action::Choose(..)) => substituted = None,
+                    _ => choices.push(Ok(stack)),
+                },
+                _ => {}
+            }
+        }
+        let name = record
+            .players()
+            .iter()
+            .find(|p| p.0 == id)
+            .map(|p| p.1.clone())
+            .unwrap_or_else(|| format!("Replay Player {}", id));
+        ReplayPlayer {
+            id,
+            name,
+            hand: Vec::new(),
+            pile: Vec::new(),
+            plays,
+            choices,
+            chosen: Cell::new(0),
+        }
+    }
+}
+
+impl Player for ReplayPlayer {
+    fn id(&self) -> Id {
+        self.id
+    }
+
+    fn hand(&self) -> &[Card] {
+        &self.hand
+    }
+
+    fn pile(&self) -> &[Card] {
+        &self.pile
+    }
+
+    fn draw(&mut self, card: Card) {
+        self.hand.push(card)
+    }
+
+    fn discard(&mut self, card: &Card) {
+        self.hand.retain(|c| c != card);
+    }
+
+    fn play(&mut self, _view: &PlayerView) -> Result<Card, PlayerError> {
+        let planned = match self.plays.pop_front() {
+            Some(Ok(card)) => self.hand.iter().position(|c| *c == card),
+            Some(Err(Offense::NotInHand(card))) => return Ok(card),
+            Some(Err(offense)) => return Err(failure(offense)),
+            None => None,
+        };
+        let i = match planned {
+            Some(i) => i,
+            None => (0..self.hand.len())
+                .min_by_key(|&i| &self.hand[i])
+                .ok_or_else(|| PlayerError::new("player ran out of cards."))?,
+        };
+        Ok(self.hand.remove(i))
+    }
+
+    fn choose(
+        &self,
+        _card: &Card,
+        _board: &Board,
+        _view: &PlayerView,
+    ) -> Result<StackId, PlayerError> {
+        let i = self.chosen.get();
+        self.chosen.set(i + 1);
+        match self.choices.get(i).cloned() {
+            Some(Ok(stack)) | Some(Err(Offense::NoSuchStack(stack))) => Ok(stack),
+            Some(Err(offense)) => Err(failure(offense)),
+            None => Ok(StackId::A),
+        }
+    }
+
+    fn take(&mut self, cards: Vec<Card>) {
+        self.pile.extend(cards);
+    }
+}
+
+impl fmt::Display for ReplayPlayer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "{}", self.name)
+    }
+}
+
+/// Returns the error a player failing with the given offense returns.
+fn failure(offense: Offense) -> PlayerError {
+    match offense {
+        Offense::Failed(reason) => PlayerError::new(reason),
+        offense => PlayerError::new(offense.to_string()),
+    }
+}
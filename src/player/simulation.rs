@@ -0,0 +1,76 @@
+use crate::player::{Id, PlayerView};
+use crate::{resolve_turn, Board, Card, Configuration, Error, Outcome, StackId};
+use rand::Rng;
+
+/// Deals the given unseen cards at random to every opponent of the
+/// view's player, as many as the player holds, or as many as are left.
+/// This is one plausible guess at what the opponents hold.
+pub(super) fn deal<R: Rng>(
+    unseen: &[Card],
+    view: &PlayerView,
+    rng: &mut R,
+) -> Vec<(Id, Vec<Card>)> {
+    let mut unseen = unseen.to_vec();
+    rng.shuffle(&mut unseen);
+    let size = view.hand().len();
+    view.scores()
+        .iter()
+        .filter(|&&(id, _)| id != view.id())
+        .map(|&(id, _)| {
+            let at = unseen.len().saturating_sub(size);
+            (id, unseen.split_off(at))
+        })
+        .collect()
+}
+
+/// Plays out the rest of a round from the given board, returning the bulls
+/// the player with the given id takes.
+///
+/// The given cards are played first, and then every player plays a random
+/// card from their hand each turn until the hands are empty. A player
+/// whose card fits no stack takes the stack with the fewest bulls. Turns
+/// are resolved with the configuration's placement, as in a game.
+pub(super) fn play_out<R: Rng>(
+    board: &Board,
+    mut played: Vec<(Card, Id)>,
+    mut hands: Vec<(Id, Vec<Card>)>,
+    id: Id,
+    config: &Configuration,
+    rng: &mut R,
+) -> Result<u32, Error> {
+    let mut board = board.clone();
+    let mut bulls = 0;
+    loop {
+        let (after, outcomes) =
+            resolve_turn(&board, &played, &config.placement, config, |b, _, _| {
+                fewest(b)
+            })?;
+        board = after;
+        for outcome in outcomes {
+            if let Outcome::Taken { player, cards, .. } = outcome {
+                if player == id {
+                    bulls += cards.iter().map(|c| u32::from(c.bull())).sum::<u32>();
+                }
+            }
+        }
+        played = hands
+            .iter_mut()
+            .filter(|(_, hand)| !hand.is_empty())
+            .map(|(id, hand)| {
+                let i = rng.gen_range(0, hand.len());
+                (hand.swap_remove(i), *id)
+            })
+            .collect();
+        if played.is_empty() {
+            return Ok(bulls);
+        }
+    }
+}
+
+/// Returns the stack of the given board with the fewest bulls.
+fn fewest(board: &Board) -> StackId {
+    board
+        .ids()
+        .min_by_key(|&id| board[id].bulls())
+        .unwrap_or(StackId::A)
+}
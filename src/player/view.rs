@@ -0,0 +1,211 @@
+use crate::observer::GameEvent;
+use crate::player::Id;
+use crate::{Board, Card, Configuration, Player};
+
+/// Everything a player may know about a game, given to them with every
+/// decision.
+///
+/// A view holds the player's own hand and pile, the board, and the public
+/// history of the game: every card revealed, every stack taken, and every
+/// player's score. Other players' hands are never part of a view, so a
+/// player can't learn what they hold. A view is a copy, and nothing done
+/// with it changes the game.
+///
+/// Games build views with `Game::view`. Outside of a game, a view of just
+/// a player and a board can be made with `PlayerView::new`.
+///
+/// # Examples
+///
+/// ```
+/// use take5::{Game, Player};
+/// use take5::dealer::StandardDealer;
+/// use take5::player::AiPlayer;
+///
+/// let players = (0..3).map(|id| {
+///     Box::new(AiPlayer::new(id)) as Box<dyn Player>
+/// }).collect();
+/// let mut game = Game::new(Box::new(StandardDealer::default()), players);
+/// let status = game.step().unwrap();
+///
+/// let view = game.view(0).unwrap();
+/// assert_eq!(view.hand(), game.hand(0).unwrap());
+/// // All but the player's hand and the 4 flipped cards are unseen.
+/// assert_eq!(view.unseen(), 90);
+///
+/// game.ask_players(&status).unwrap();
+/// game.step().unwrap();
+/// let view = game.view(0).unwrap();
+/// assert_eq!(view.played().count(), 3);
+/// assert_eq!(view.unseen(), 88);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlayerView {
+    pub(crate) id: Id,
+    pub(crate) hand: Vec<Card>,
+    pub(crate) pile: Vec<Card>,
+    pub(crate) board: Board,
+    pub(crate) configuration: Configuration,
+    pub(crate) scores: Vec<(Id, u32)>,
+    pub(crate) history: Vec<GameEvent>,
+    pub(crate) deck: usize,
+}
+
+impl PlayerView {
+    /// Creates a view of the given player and board alone, without any
+    /// history, as when asking a player for a decision outside of a game.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use take5::{Board, Card, Configuration, Player};
+    /// use take5::player::{AiPlayer, PlayerView};
+    ///
+    /// let config = Configuration::default();
+    /// let deck = Card::deck(&config, |_| 1);
+    /// let mut ai = AiPlayer::new(1);
+    /// ai.draw(deck[0].clone());
+    ///
+    /// let view = PlayerView::new(&ai, &Board::default(), &config);
+    /// assert_eq!(view.hand(), &deck[..1]);
+    /// assert_eq!(view.scores(), &[(1, 0)]);
+    /// assert_eq!(view.unseen(), 103);
+    /// ```
+    pub fn new(player: &dyn Player, board: &Board, configuration: &Configuration) -> Self {
+        PlayerView {
+            id: player.id(),
+            hand: player.hand().to_vec(),
+            pile: player.pile().to_vec(),
+            board: board.clone(),
+            configuration: configuration.clone(),
+            scores: vec![(player.id(), player.score())],
+            history: Vec::new(),
+            deck: configuration.deck_size as usize,
+        }
+    }
+
+    /// Returns the id of the player this view is for.
+    pub fn id(&self) -> Id {
+        self.id
+    }
+
+    /// Returns the cards the player has yet to play this round.
+    pub fn hand(&self) -> &[Card] {
+        &self.hand
+    }
+
+    /// Returns every card the player has taken.
+    pub fn pile(&self) -> &[Card] {
+        &self.pile
+    }
+
+    /// Returns the board.
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    /// Returns the configuration of the game.
+    pub fn configuration(&self) -> &Configuration {
+        &self.configuration
+    }
+
+    /// Returns every player's score, counting the bulls they took this
+    /// round, in seat order.
+    pub fn scores(&self) -> &[(Id, u32)] {
+        &self.scores
+    }
+
+    /// Returns every public event of the game so far, in order. The hands
+    /// dealt to the players are private, so `GameEvent::HandsDealt` is
+    /// never part of the history, and neither is the dealer's seed, which
+    /// `GameEvent::GameStarted` leaves out, or a card picked for a
+    /// penalized player before it's revealed.
+    pub fn history(&self) -> &[GameEvent] {
+        &self.history
+    }
+
+    /// Returns every card revealed this round, with the id of the player
+    /// who played it, in the order they were revealed.
+    pub fn played(&self) -> impl Iterator<Item = &(Card, Id)> {
+        self.round().iter().flat_map(|event| match *event {
+            GameEvent::CardsRevealed { ref played } => &played[..],
+            _ => &[],
+        })
+    }
+
+    /// Returns the cards revealed this turn which are placed after the given
+    /// card, with the id of the player who played each, lowest first. When
+    /// a player chooses a stack to take, these are the rest of the turn.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use take5::{Board, Card, Configuration, Player};
+    /// use take5::player::{AiPlayer, PlayerView};
+    ///
+    /// // Out of a game no cards were revealed.
+    /// let config = Configuration::default();
+    /// let deck = Card::deck(&config, |_| 1);
+    /// let ai = AiPlayer::new(0);
+    /// let view = PlayerView::new(&ai, &Board::default(), &config);
+    /// assert!(view.pending(&deck[0]).is_empty());
+    /// ```
+    pub fn pending(&self, card: &Card) -> Vec<(Card, Id)> {
+        self.history
+            .iter()
+            .rev()
+            .find_map(|event| match *event {
+                GameEvent::CardsRevealed { ref played } => Some(played),
+                _ => None,
+            })
+            .map(|played| played.iter().filter(|p| p.0 > *card).cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns every stack taken this round, as the id of the player who
+    /// took it and its cards, in the order they were taken.
+    pub fn taken(&self) -> impl Iterator<Item = (Id, &[Card])> {
+        self.round().iter().filter_map(|event| match *event {
+            GameEvent::StackTaken {
+                player, ref cards, ..
+            } => Some((player, &cards[..])),
+            _ => None,
+        })
+    }
+
+    /// Returns every card of this round's deck the player has seen: their
+    /// hand, the board as it was flipped, and every card revealed since.
+    /// Without a history, only the hand and the board are seen.
+    pub fn seen(&self) -> Vec<&Card> {
+        let flipped = self.round().iter().find_map(|event| match *event {
+            GameEvent::BoardFlipped { ref board } => Some(board),
+            _ => None,
+        });
+        let mut seen: Vec<&Card> = self.hand.iter().collect();
+        match flipped {
+            Some(board) => {
+                seen.extend(board.into_iter().flat_map(|s| s.iter()));
+                seen.extend(self.played().map(|p| &p.0));
+            }
+            None => seen.extend(self.board.into_iter().flat_map(|s| s.iter())),
+        }
+        seen
+    }
+
+    /// Returns the number of cards in this round's deck the player hasn't
+    /// seen: the cards still in other players' hands, and the cards never
+    /// dealt.
+    pub fn unseen(&self) -> usize {
+        self.deck.saturating_sub(self.seen().len())
+    }
+
+    /// Returns the events of the round being played, or the last round
+    /// played.
+    fn round(&self) -> &[GameEvent] {
+        let start = self
+            .history
+            .iter()
+            .rposition(|event| matches!(*event, GameEvent::RoundStarted { .. }))
+            .unwrap_or(self.history.len());
+        &self.history[start..]
+    }
+}
@@ -0,0 +1,399 @@
+use crate::dealer::ReplayDealer;
+use crate::end_condition::FixedRounds;
+use crate::observer::{GameEvent, GameObserver};
+use crate::player::{Id, ReplayPlayer};
+use crate::{Configuration, Game, GameResult, Player, TimeLimit};
+use std::io::{self, BufRead, Write};
+use std::sync::mpsc::channel;
+use std::{error, fmt};
+
+/// The header line starting every record.
+#[derive(Serialize, Deserialize)]
+struct Header {
+    version: u32,
+}
+
+/// A complete recording of a game, as every event which happened in it.
+///
+/// Records are stored as one JSON value per line. The first line is a
+/// header with the version of the format, `{"version":1}`, and every line
+/// after it is a single `GameEvent`, from `GameStarted` to `GameEnded`. A
+/// record can be written while a game is played with a `Recorder`.
+///
+/// # Examples
+///
+/// ```
+/// use std::fs::File;
+/// use std::io::BufReader;
+/// use take5::{Game, GameRecord, Recorder};
+/// use take5::dealer::StandardDealer;
+/// use take5::player::{AiPlayer, Player};
+///
+/// let path = std::env::temp_dir().join("take5-record-example.jsonl");
+///
+/// // Record a game.
+/// let players = (0..4).map(|id| {
+///     Box::new(AiPlayer::new(id)) as Box<dyn Player>
+/// }).collect();
+/// let mut game = Game::new(Box::new(StandardDealer::default()), players);
+/// let recorder = Recorder::new(File::create(&path).unwrap()).unwrap();
+/// game.observers.push(Box::new(recorder));
+/// let result = game.run();
+///
+/// // Read it back, and replay it.
+/// let record = GameRecord::read(BufReader::new(File::open(&path).unwrap())).unwrap();
+/// assert_eq!(record.result(), &result);
+/// assert_eq!(record.replay().unwrap(), result);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameRecord {
+    events: Vec<GameEvent>,
+}
+
+impl GameRecord {
+    /// The version of the record format written by this crate.
+    pub const VERSION: u32 = 1;
+
+    /// Creates a record from the events of a game.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RecordError::Incomplete` unless the events start with
+    /// `GameStarted` and end with `GameEnded`.
+    pub fn new(events: Vec<GameEvent>) -> Result<GameRecord, RecordError> {
+        match (events.first(), events.last()) {
+            (Some(&GameEvent::GameStarted { .. }), Some(&GameEvent::GameEnded { .. })) => {
+                Ok(GameRecord { events })
+            }
+            _ => Err(RecordError::Incomplete),
+        }
+    }
+
+    /// Reads a record, one line at a time.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the record cannot be read, a line isn't valid, the
+    /// record was written with another version of the format, or the
+    /// record doesn't hold a complete game.
+    pub fn read<R: BufRead>(reader: R) -> Result<GameRecord, RecordError> {
+        let mut lines = reader.lines();
+        let header: Header = match lines.next() {
+            Some(line) => parse(&line?, 1)?,
+            None => return Err(RecordError::Incomplete),
+        };
+        if header.version != GameRecord::VERSION {
+            return Err(RecordError::Version(header.version));
+        }
+        let mut events = Vec::new();
+        for (i, line) in lines.enumerate() {
+            let line = line?;
+            if !line.trim().is_empty() {
+                events.push(parse(&line, i + 2)?);
+            }
+        }
+        GameRecord::new(events)
+    }
+
+    /// Writes this record, one line at a time.
+    pub fn write<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        write_line(
+            &mut writer,
+            &Header {
+                version: GameRecord::VERSION,
+            },
+        )?;
+        for event in &self.events {
+            write_line(&mut writer, event)?;
+        }
+        Ok(())
+    }
+
+    /// Returns every event of the recorded game, in order.
+    pub fn events(&self) -> &[GameEvent] {
+        &self.events
+    }
+
+    /// Returns the configuration the recorded game was played with.
+    pub fn configuration(&self) -> &Configuration {
+        match self.events[0] {
+            GameEvent::GameStarted {
+                ref configuration, ..
+            } => configuration,
+            _ => unreachable!("records start with `GameStarted`."),
+        }
+    }
+
+    /// Returns the ids and names of the recorded players, in seat order.
+    pub fn players(&self) -> &[(Id, String)] {
+        match self.events[0] {
+            GameEvent::GameStarted { ref players, .. } => players,
+            _ => unreachable!("records start with `GameStarted`."),
+        }
+    }
+
+    /// Returns the seed the recorded game was dealt with, if it had one.
+    pub fn seed(&self) -> Option<u64> {
+        match self.events[0] {
+            GameEvent::GameStarted { seed, .. } => seed,
+            _ => unreachable!("records start with `GameStarted`."),
+        }
+    }
+
+    /// Returns the number of rounds in the recorded game.
+    pub fn rounds(&self) -> usize {
+        self.events
+            .iter()
+            .filter(|e| matches!(**e, GameEvent::RoundStarted { .. }))
+            .count()
+    }
+
+    /// Returns the result of the recorded game.
+    pub fn result(&self) -> &GameResult {
+        match self.events[self.events.len() - 1] {
+            GameEvent::GameEnded { ref result } => result,
+            _ => unreachable!("records end with `GameEnded`."),
+        }
+    }
+
+    /// Replays the recorded game with a `ReplayDealer` and a `ReplayPlayer`
+    /// in each seat, returning the replayed result. Players who broke the
+    /// rules break them again, and are given the card or stack picked for
+    /// them in the record.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RecordError::Mismatch` with the first event of the replay
+    /// which differs from the record, so every board, take and score of
+    /// the replay matches the record exactly when this returns `Ok`.
+    ///
+    /// The replay isn't timed, even when the recorded game had a
+    /// `TimeLimit`, so the time the players used is left out of the
+    /// comparison, and the replayed result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::mpsc::channel;
+    /// use take5::{Game, GameRecord, RecordError};
+    /// use take5::dealer::StandardDealer;
+    /// use take5::observer::GameEvent;
+    /// use take5::player::{AiPlayer, Player};
+    ///
+    /// let players = (0..3).map(|id| {
+    ///     Box::new(AiPlayer::new(id)) as Box<dyn Player>
+    /// }).collect();
+    /// let mut game = Game::new(Box::new(StandardDealer::seeded(1)), players);
+    /// let (sender, receiver) = channel();
+    /// game.observers.push(Box::new(sender));
+    /// game.run();
+    /// let mut events: Vec<GameEvent> = receiver.try_iter().collect();
+    /// assert!(GameRecord::new(events.clone()).unwrap().replay().is_ok());
+    ///
+    /// // Tamper with the scores of the first round.
+    /// let i = events.iter().position(|e| match *e {
+    ///     GameEvent::RoundEnded { .. } => true,
+    ///     _ => false,
+    /// }).unwrap();
+    /// events[i] = GameEvent::RoundEnded { round: 0, scores: vec![] };
+    /// match GameRecord::new(events).unwrap().replay() {
+    ///     Err(RecordError::Mismatch { line, .. }) => assert_eq!(line, i + 2),
+    ///     _ => panic!("expected the replay to differ"),
+    /// }
+    /// ```
+    ///
+    /// Replaying games where a player broke the rules.
+    ///
+    /// ```
+    /// use std::sync::mpsc::channel;
+    /// use take5::{Configuration, Game, GameRecord, Offense, Penalty};
+    /// use take5::dealer::StandardDealer;
+    /// use take5::player::{AiPlayer, Player};
+    ///
+    /// for &penalty in &[Penalty::Substitute, Penalty::Eject] {
+    ///     let config = Configuration { penalty, ..Configuration::default() };
+    ///     let players = (0..3).map(|id| {
+    ///         Box::new(AiPlayer::new(id)) as Box<dyn Player>
+    ///     }).collect();
+    ///     let dealer = Box::new(StandardDealer::default());
+    ///     let mut game = Game::with_configuration(config, dealer, players);
+    ///     let (sender, receiver) = channel();
+    ///     game.observers.push(Box::new(sender));
+    ///     game.step().unwrap();
+    ///     game.penalize(1, Offense::Failed("timed out".into())).unwrap();
+    ///     let result = game.run();
+    ///     assert_eq!(result.violations.len(), 1);
+    ///
+    ///     let record = GameRecord::new(receiver.try_iter().collect()).unwrap();
+    ///     assert_eq!(record.replay().unwrap(), result);
+    /// }
+    /// ```
+    pub fn replay(&self) -> Result<GameResult, RecordError> {
+        let players = self
+            .players()
+            .iter()
+            .map(|&(id, _)| Box::new(ReplayPlayer::new(self, id)) as Box<dyn Player>)
+            .collect();
+        let mut game = Game::with_configuration(
+            self.configuration().clone(),
+            Box::new(ReplayDealer::new(self)),
+            players,
+        );
+        game.end_condition = Box::new(FixedRounds(self.rounds()));
+        game.time_limit = TimeLimit::default();
+        game.substitutes = self
+            .events
+            .iter()
+            .filter_map(|event| match *event {
+                GameEvent::PlayerPenalized { ref substitute, .. } => substitute.clone(),
+                _ => None,
+            })
+            .collect();
+        let (sender, receiver) = channel();
+        game.observers.push(Box::new(sender));
+        let result = game.run();
+
+        let mut replayed = receiver.try_iter();
+        let mut recorded = self.events.iter();
+        for line in 2.. {
+            match (recorded.next(), replayed.next()) {
+                (None, None) => break,
+                (Some(expected), Some(ref found)) if untimed(expected) == untimed(found) => {}
+                (expected, found) => {
+                    return Err(RecordError::Mismatch {
+                        line,
+                        expected: expected.cloned().map(Box::new),
+                        found: found.map(Box::new),
+                    })
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// An observer writing the record of a game as it's played.
+///
+/// Every event is written and flushed as soon as it happens. If writing
+/// fails the recorder stops, and reading the record back reports it as
+/// incomplete. See `GameRecord` for an example.
+#[derive(Debug)]
+pub struct Recorder<W: Write> {
+    writer: W,
+    failed: bool,
+}
+
+impl<W: Write> Recorder<W> {
+    /// Creates a recorder writing to the given writer, writing the record's
+    /// header right away.
+    pub fn new(mut writer: W) -> io::Result<Self> {
+        write_line(
+            &mut writer,
+            &Header {
+                version: GameRecord::VERSION,
+            },
+        )?;
+        Ok(Recorder {
+            writer,
+            failed: false,
+        })
+    }
+}
+
+impl<W: Write> GameObserver for Recorder<W> {
+    fn notify(&mut self, event: &GameEvent) {
+        if !self.failed {
+            self.failed = write_line(&mut self.writer, event).is_err();
+        }
+    }
+}
+
+/// Returns the given event without the time the players used.
+fn untimed(event: &GameEvent) -> GameEvent {
+    let mut event = event.clone();
+    if let GameEvent::GameEnded { ref mut result } = event {
+        for standing in result.standings.iter_mut() {
+            standing.time = None;
+        }
+    }
+    event
+}
+
+fn write_line<W: Write, T: serde::Serialize>(writer: &mut W, value: &T) -> io::Result<()> {
+    serde_json::to_writer(&mut *writer, value)?;
+    writer.write_all(b"\n")?;
+    writer.flush()
+}
+
+fn parse<'a, T: serde::Deserialize<'a>>(line: &'a str, number: usize) -> Result<T, RecordError> {
+    serde_json::from_str(line).map_err(|e| RecordError::Parse {
+        line: number,
+        error: e,
+    })
+}
+
+/// An error reading or replaying a `GameRecord`.
+#[derive(Debug)]
+pub enum RecordError {
+    /// The record couldn't be read.
+    Io(io::Error),
+    /// The given line of the record isn't a valid header or event.
+    Parse {
+        line: usize,
+        error: serde_json::Error,
+    },
+    /// The record was written with the given, unsupported, version.
+    Version(u32),
+    /// The record doesn't hold a game from start to end.
+    Incomplete,
+    /// The replay differed from the event on the given line of the record.
+    /// Either event is `None` when the record or the replay ended early.
+    Mismatch {
+        line: usize,
+        expected: Option<Box<GameEvent>>,
+        found: Option<Box<GameEvent>>,
+    },
+}
+
+impl fmt::Display for RecordError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            RecordError::Io(ref e) => write!(f, "could not read record: {}", e),
+            RecordError::Parse { line, ref error } => {
+                write!(f, "could not parse record line {}: {}", line, error)
+            }
+            RecordError::Version(v) => write!(
+                f,
+                "unsupported record version {}, expected {}",
+                v,
+                GameRecord::VERSION
+            ),
+            RecordError::Incomplete => write!(f, "record doesn't hold a complete game"),
+            RecordError::Mismatch {
+                line,
+                ref expected,
+                ref found,
+            } => write!(
+                f,
+                "replay differs from record line {}: expected {:?}, found {:?}",
+                line, expected, found
+            ),
+        }
+    }
+}
+
+impl error::Error for RecordError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            RecordError::Io(ref e) => Some(e),
+            RecordError::Parse { ref error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for RecordError {
+    fn from(e: io::Error) -> Self {
+        RecordError::Io(e)
+    }
+}
@@ -0,0 +1,165 @@
+use crate::player::Id;
+use crate::Error;
+
+/// The bulls each player took in every round of a game.
+///
+/// Scores are kept per round, in the order of the players given when
+/// creating the sheet.
+///
+/// # Examples
+///
+/// ```
+/// use take5::ScoreSheet;
+///
+/// let mut sheet = ScoreSheet::new(vec![0, 1, 2]);
+/// sheet.record(vec![5, 3, 3]).unwrap();
+/// sheet.record(vec![1, 9, 2]).unwrap();
+/// assert_eq!(sheet.round_count(), 2);
+/// assert_eq!(sheet.totals(), vec![(0, 6), (1, 12), (2, 5)]);
+/// assert_eq!(sheet.leaders(), vec![2]);
+/// assert_eq!(sheet.round_winners(0), vec![1, 2]);
+/// assert_eq!(sheet.rounds_won(2), 1);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScoreSheet {
+    players: Vec<Id>,
+    rounds: Vec<Vec<u32>>,
+}
+
+impl ScoreSheet {
+    /// Creates an empty score sheet for the given players.
+    pub fn new(players: Vec<Id>) -> Self {
+        ScoreSheet {
+            players,
+            rounds: Vec::new(),
+        }
+    }
+
+    /// Returns the players on this score sheet.
+    pub fn players(&self) -> &[Id] {
+        &self.players
+    }
+
+    /// Records the bulls each player took in a round.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::ScoreCount` if the number of scores doesn't match
+    /// the number of players, and records nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use take5::{Error, ScoreSheet};
+    ///
+    /// let mut sheet = ScoreSheet::new(vec![0, 1]);
+    /// assert_eq!(
+    ///     sheet.record(vec![5]),
+    ///     Err(Error::ScoreCount { expected: 2, found: 1 }),
+    /// );
+    /// assert_eq!(sheet.round_count(), 0);
+    /// ```
+    pub fn record(&mut self, scores: Vec<u32>) -> Result<(), Error> {
+        if scores.len() != self.players.len() {
+            return Err(Error::ScoreCount {
+                expected: self.players.len(),
+                found: scores.len(),
+            });
+        }
+        self.rounds.push(scores);
+        Ok(())
+    }
+
+    /// Returns this score sheet without the given players, keeping every
+    /// round.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use take5::ScoreSheet;
+    ///
+    /// let mut sheet = ScoreSheet::new(vec![0, 1, 2]);
+    /// sheet.record(vec![5, 3, 4]).unwrap();
+    /// let sheet = sheet.without(&[1]);
+    /// assert_eq!(sheet.totals(), vec![(0, 5), (2, 4)]);
+    /// assert_eq!(sheet.leaders(), vec![2]);
+    /// ```
+    pub fn without(&self, ids: &[Id]) -> ScoreSheet {
+        let kept: Vec<usize> = (0..self.players.len())
+            .filter(|&i| !ids.contains(&self.players[i]))
+            .collect();
+        ScoreSheet {
+            players: kept.iter().map(|&i| self.players[i]).collect(),
+            rounds: self
+                .rounds
+                .iter()
+                .map(|round| kept.iter().map(|&i| round[i]).collect())
+                .collect(),
+        }
+    }
+
+    /// Returns the number of rounds recorded.
+    pub fn round_count(&self) -> usize {
+        self.rounds.len()
+    }
+
+    /// Returns the bulls each player took in the given round, paired with
+    /// the player's `Id`, or `None` if the round wasn't recorded.
+    pub fn round(&self, round: usize) -> Option<Vec<(Id, u32)>> {
+        let scores = self.rounds.get(round)?;
+        Some(
+            self.players
+                .iter()
+                .cloned()
+                .zip(scores.iter().cloned())
+                .collect(),
+        )
+    }
+
+    /// Returns the total bulls of the given player, or 0 if the player
+    /// isn't on this sheet.
+    pub fn total(&self, id: Id) -> u32 {
+        match self.players.iter().position(|&p| p == id) {
+            Some(i) => self.rounds.iter().map(|round| round[i]).sum(),
+            None => 0,
+        }
+    }
+
+    /// Returns the total bulls of every player, paired with the player's
+    /// `Id`.
+    pub fn totals(&self) -> Vec<(Id, u32)> {
+        self.players
+            .iter()
+            .map(|&id| (id, self.total(id)))
+            .collect()
+    }
+
+    /// Returns every player with the lowest total. There is more than one
+    /// leader when players are tied.
+    pub fn leaders(&self) -> Vec<Id> {
+        lowest(self.totals())
+    }
+
+    /// Returns every player with the lowest score in the given round, or
+    /// no one if the round wasn't recorded.
+    pub fn round_winners(&self, round: usize) -> Vec<Id> {
+        self.round(round).map(lowest).unwrap_or_default()
+    }
+
+    /// Returns the number of rounds the given player won, including rounds
+    /// they tied for the lowest score.
+    pub fn rounds_won(&self, id: Id) -> usize {
+        (0..self.round_count())
+            .filter(|&round| self.round_winners(round).contains(&id))
+            .count()
+    }
+}
+
+fn lowest(scores: Vec<(Id, u32)>) -> Vec<Id> {
+    let min = scores.iter().map(|&(_, score)| score).min();
+    scores
+        .into_iter()
+        .filter(|&(_, score)| Some(score) == min)
+        .map(|(id, _)| id)
+        .collect()
+}
@@ -0,0 +1,249 @@
+use crate::game::Phase;
+use crate::observer::GameEvent;
+use crate::player::Id;
+use crate::{Board, Card, Configuration, Dealer, Game, Player, ScoreSheet, Violation};
+use itertools::Itertools;
+use std::time::Duration;
+use std::{error, fmt};
+
+/// A player's seat in a `GameSnapshot`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayerSnapshot {
+    /// The player's id.
+    pub id: Id,
+    /// The player's name, as displayed.
+    pub name: String,
+    /// The cards the player has yet to play this round.
+    pub hand: Vec<Card>,
+    /// Every card the player has taken.
+    pub pile: Vec<Card>,
+    /// The player's score.
+    pub score: u32,
+    /// The time the player has taken to decide, counted against a
+    /// `TimeLimit`.
+    #[serde(default)]
+    pub time: Duration,
+}
+
+/// Everything needed to resume a game where it was left off.
+///
+/// Unlike a `Game`, a snapshot holds no dealer or player objects, so it
+/// can be serialized to save a game, and later resumed with new ones with
+/// `Game::resume`.
+///
+/// # Examples
+///
+/// ```
+/// use take5::{Game, GameSnapshot, Player, Status};
+/// use take5::dealer::StandardDealer;
+/// use take5::player::AiPlayer;
+///
+/// let players = || (0..3).map(|id| {
+///     Box::new(AiPlayer::new(id)) as Box<dyn Player>
+/// }).collect();
+/// let mut game = Game::new(Box::new(StandardDealer::seeded(2)), players());
+/// game.step().unwrap();
+///
+/// // Save the game as JSON, and load it again.
+/// let json = serde_json::to_string(&game.snapshot()).unwrap();
+/// let snapshot: GameSnapshot = serde_json::from_str(&json).unwrap();
+/// assert_eq!(snapshot.players[0].hand.len(), 10);
+///
+/// let mut game = Game::resume(snapshot, Box::new(StandardDealer::default()), players()).unwrap();
+/// assert_eq!(game.step(), Ok(Status::NeedPlay(vec![0, 1, 2])));
+/// assert_eq!(game.players[0].hand().len(), 10);
+/// game.run();
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GameSnapshot {
+    /// The configuration of the game.
+    pub configuration: Configuration,
+    /// The seed the dealer shuffled with, if it had one.
+    pub seed: Option<u64>,
+    /// The cards left in the dealer's deck.
+    pub deck: Vec<Card>,
+    /// The board.
+    pub board: Board,
+    /// The players, in seat order.
+    pub players: Vec<PlayerSnapshot>,
+    /// The bulls each player took in every finished round.
+    pub scores: ScoreSheet,
+    /// The round being played, counting from 0.
+    pub round: usize,
+    /// The turn being played in the round, counting from 0.
+    pub turn: usize,
+    /// Every rule a player broke so far.
+    #[serde(default)]
+    pub violations: Vec<Violation>,
+    /// Every public event of the game so far, which the players' views
+    /// are built from.
+    #[serde(default)]
+    pub history: Vec<GameEvent>,
+    before: Vec<u32>,
+    phase: Phase,
+}
+
+impl Game {
+    /// Takes a snapshot of this game, which can be resumed later.
+    pub fn snapshot(&self) -> GameSnapshot {
+        GameSnapshot {
+            configuration: self.configuration.clone(),
+            seed: self.dealer.seed(),
+            deck: self.dealer.deck().to_vec(),
+            board: self.board.clone(),
+            players: self
+                .players
+                .iter()
+                .zip(&self.hands)
+                .zip(&self.clocks)
+                .map(|((p, hand), clock)| PlayerSnapshot {
+                    id: p.id(),
+                    name: p.to_string(),
+                    hand: hand.clone(),
+                    pile: p.pile().to_vec(),
+                    score: p.score(),
+                    time: clock.used,
+                })
+                .collect(),
+            scores: self.scores.clone(),
+            round: self.scores.round_count(),
+            turn: self.turn,
+            violations: self.violations.clone(),
+            history: self.history.clone(),
+            before: self.before.clone(),
+            phase: self.phase.clone(),
+        }
+    }
+
+    /// Resumes the game saved in the given snapshot, with a new dealer
+    /// and new players, as `Game::restore`.
+    ///
+    /// The game ends with the default end condition for the snapshot's
+    /// configuration, and has no observers. To resume with others, create
+    /// the game and `restore` the snapshot into it instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the players don't match the snapshot's players.
+    pub fn resume(
+        snapshot: GameSnapshot,
+        dealer: Box<dyn Dealer>,
+        players: Vec<Box<dyn Player>>,
+    ) -> Result<Game, SnapshotError> {
+        let mut game = Game::with_configuration(snapshot.configuration.clone(), dealer, players);
+        game.restore(snapshot)?;
+        Ok(game)
+    }
+
+    /// Restores the game saved in the given snapshot into this game, which
+    /// must not have started. The game's players must have the ids of the
+    /// snapshot's players, in the same order. They're told the game
+    /// started, and the round being played, and are given the cards they
+    /// held and took. The dealer is given the deck.
+    ///
+    /// The game is played with the snapshot's configuration, and keeps
+    /// its own end condition and observers. A seeded dealer isn't restored
+    /// to where it was, so later rounds are dealt differently than they
+    /// would've been.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the game has started, or if the players don't
+    /// match the snapshot's players.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use take5::{Game, Player};
+    /// use take5::dealer::StandardDealer;
+    /// use take5::end_condition::FixedRounds;
+    /// use take5::player::AiPlayer;
+    ///
+    /// let players = || (0..3).map(|id| {
+    ///     Box::new(AiPlayer::new(id)) as Box<dyn Player>
+    /// }).collect();
+    /// let mut game = Game::new(Box::new(StandardDealer::seeded(2)), players());
+    /// game.step().unwrap();
+    /// let snapshot = game.snapshot();
+    ///
+    /// let mut game = Game::new(Box::new(StandardDealer::default()), players());
+    /// game.end_condition = Box::new(FixedRounds(2));
+    /// game.restore(snapshot.clone()).unwrap();
+    /// assert_eq!(game.run().rounds, 2);
+    ///
+    /// // A game can't be restored once it's started.
+    /// assert!(game.restore(snapshot).is_err());
+    /// ```
+    pub fn restore(&mut self, snapshot: GameSnapshot) -> Result<(), SnapshotError> {
+        if !matches!(self.phase, Phase::New) {
+            return Err(SnapshotError::Started);
+        }
+        let expected: Vec<Id> = snapshot.players.iter().map(|p| p.id).collect();
+        let found: Vec<Id> = self.players.iter().map(|p| p.id()).collect();
+        if expected != found {
+            return Err(SnapshotError::Players { expected, found });
+        }
+
+        // A new game tells its players it started on its first step.
+        let started = !matches!(snapshot.phase, Phase::New);
+        let playing = matches!(snapshot.phase, Phase::Playing(_) | Phase::Placing(..));
+        let mut hands = Vec::new();
+        let seats = self.players.iter_mut().zip(&mut self.clocks);
+        for ((player, clock), seat) in seats.zip(snapshot.players) {
+            if started {
+                player.start_game(&expected);
+            }
+            if playing {
+                player.start_round(snapshot.round);
+            }
+            for card in seat.hand.iter().cloned() {
+                player.draw(card);
+            }
+            player.take(seat.pile);
+            hands.push(seat.hand);
+            clock.used = seat.time;
+        }
+        self.dealer.restore(snapshot.deck);
+
+        self.board = snapshot.board;
+        self.time_limit = snapshot.configuration.time_limit;
+        self.configuration = snapshot.configuration;
+        self.scores = snapshot.scores;
+        self.hands = hands;
+        self.before = snapshot.before;
+        self.turn = snapshot.turn;
+        self.violations = snapshot.violations;
+        self.history = snapshot.history;
+        self.phase = snapshot.phase;
+        Ok(())
+    }
+}
+
+/// An error resuming a game from a `GameSnapshot`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SnapshotError {
+    /// The ids of the given players don't match the ids of the players
+    /// in the snapshot.
+    Players { expected: Vec<Id>, found: Vec<Id> },
+    /// The game to restore the snapshot into has already started.
+    Started,
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            SnapshotError::Players {
+                ref expected,
+                ref found,
+            } => write!(
+                f,
+                "expected players {}, found players {}",
+                expected.iter().join(", "),
+                found.iter().join(", ")
+            ),
+            SnapshotError::Started => write!(f, "the game has already started"),
+        }
+    }
+}
+
+impl error::Error for SnapshotError {}
@@ -1,32 +1,34 @@
-use crate::{Card, Configuration};
+use crate::{Card, Configuration, Error};
 use std::{fmt, ops};
 
 /// A stack of cards you can put cards on and take all of.
 ///
 /// Don't think of this as a general purpose computer science stack, it's
-/// a more specific construct. This stack can only have 5 cards in it,
-/// and often gives up all 5 cards and replaces them with a new empty stack.
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+/// a more specific construct. This stack can only have
+/// `Configuration::stack_size` cards in it, and often gives up all of its
+/// cards and replaces them with a new empty stack.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Stack(Vec<Card>);
 
 impl Stack {
-    /// Returns true when this stack has 5 elements in it.
+    /// Returns true when this stack has `config.stack_size` elements in it.
     ///
     /// # Examples
     ///
     /// ```
     /// use take5::{Card, Stack, Configuration};
     ///
-    /// let mut deck = Card::deck(|_| 3);
+    /// let config = Configuration::default();
+    /// let mut deck = Card::deck(&config, |_| 3);
     /// let mut stack = Stack::default();
-    /// assert!(!stack.full());
-    /// for _ in 0..Configuration::stack_size() {
-    ///     stack.push(deck.pop().unwrap());
+    /// assert!(!stack.full(&config));
+    /// for _ in 0..config.stack_size {
+    ///     stack.push(deck.pop().unwrap(), &config);
     /// }
-    /// assert!(stack.full());
+    /// assert!(stack.full(&config));
     /// ```
-    pub fn full(&self) -> bool {
-        self.0.len() == Configuration::stack_size()
+    pub fn full(&self, config: &Configuration) -> bool {
+        self.0.len() == config.stack_size
     }
 
     /// Returns the sum of the bull values of cards in the stack.
@@ -34,12 +36,13 @@ impl Stack {
     /// # Examples
     ///
     /// ```
-    /// use take5::{Card, Stack};
+    /// use take5::{Card, Configuration, Stack};
     ///
-    /// let mut deck = Card::deck(|_| 3);
+    /// let config = Configuration::default();
+    /// let mut deck = Card::deck(&config, |_| 3);
     /// let mut stack = Stack::default();
     /// for _ in 0..5 {
-    ///     stack.push(deck.pop().unwrap());
+    ///     stack.push(deck.pop().unwrap(), &config);
     /// }
     /// assert!(stack.bulls() > 0);
     /// ```
@@ -49,6 +52,33 @@ impl Stack {
 
     /// Add a card to the top of the stack.
     ///
+    /// # Errors
+    ///
+    /// Returns `Error::StackFull` when pushing onto a full stack, leaving
+    /// the stack as it was.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use take5::{Card, Configuration, Error, Stack};
+    ///
+    /// let config = Configuration { stack_size: 1, ..Configuration::default() };
+    /// let mut deck = Card::deck(&config, |_| 3);
+    /// let mut stack = Stack::default();
+    /// assert_eq!(stack.try_push(deck.pop().unwrap(), &config), Ok(()));
+    /// assert_eq!(stack.try_push(deck.pop().unwrap(), &config), Err(Error::StackFull));
+    /// assert_eq!(stack.len(), 1);
+    /// ```
+    pub fn try_push(&mut self, card: Card, config: &Configuration) -> Result<(), Error> {
+        if self.full(config) {
+            return Err(Error::StackFull);
+        }
+        self.0.push(card);
+        Ok(())
+    }
+
+    /// Add a card to the top of the stack, as `try_push`.
+    ///
     /// # Panics
     ///
     /// This function panics if pushing onto a full stack.
@@ -58,18 +88,56 @@ impl Stack {
     /// ```
     /// use take5::{Card, Stack, Configuration};
     ///
-    /// let mut deck = Card::deck(|_| 3);
+    /// let config = Configuration::default();
+    /// let mut deck = Card::deck(&config, |_| 3);
     /// let mut stack = Stack::default();
-    /// for _ in 0..Configuration::stack_size() {
-    ///     stack.push(deck.pop().unwrap());
+    /// for _ in 0..config.stack_size {
+    ///     stack.push(deck.pop().unwrap(), &config);
     /// }
-    /// assert_eq!(stack.len(), Configuration::stack_size());
+    /// assert_eq!(stack.len(), config.stack_size);
     /// ```
-    pub fn push(&mut self, card: Card) {
-        if self.full() {
-            panic!("Attempt to push onto a full stack.");
+    pub fn push(&mut self, card: Card, config: &Configuration) {
+        self.try_push(card, config)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Add a card to the bottom of the stack, as allowed by the
+    /// professional placement rule.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::StackFull` when pushing onto a full stack, leaving
+    /// the stack as it was.
+    pub fn try_push_front(&mut self, card: Card, config: &Configuration) -> Result<(), Error> {
+        if self.full(config) {
+            return Err(Error::StackFull);
         }
-        self.0.push(card);
+        self.0.insert(0, card);
+        Ok(())
+    }
+
+    /// Add a card to the bottom of the stack, as `try_push_front`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if pushing onto a full stack.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use take5::{Card, Stack, Configuration};
+    ///
+    /// let config = Configuration::default();
+    /// let mut deck = Card::deck(&config, |_| 3);
+    /// let mut stack = Stack::default();
+    /// stack.push(deck.pop().unwrap(), &config);
+    /// stack.push_front(deck.remove(0), &config);
+    /// assert_eq!(stack[0].face(), 1);
+    /// assert_eq!(stack[1].face(), 104);
+    /// ```
+    pub fn push_front(&mut self, card: Card, config: &Configuration) {
+        self.try_push_front(card, config)
+            .unwrap_or_else(|e| panic!("{}", e))
     }
 
     /// Returns the stack as a vector of cards, and creates a new empty
@@ -78,12 +146,13 @@ impl Stack {
     /// # Examples
     ///
     /// ```
-    /// use take5::{Card, Stack};
+    /// use take5::{Card, Configuration, Stack};
     ///
-    /// let mut deck = Card::deck(|_| 3);
+    /// let config = Configuration::default();
+    /// let mut deck = Card::deck(&config, |_| 3);
     /// let mut stack = Stack::default();
     /// for _ in 0..5 {
-    ///     stack.push(deck.pop().unwrap());
+    ///     stack.push(deck.pop().unwrap(), &config);
     /// }
     /// let cards = stack.give();
     /// assert_eq!(cards.len(), 5);
@@ -0,0 +1,160 @@
+use crate::observer::{GameEvent, TakeReason};
+use crate::placement::{End, PlacementRule, Position};
+use crate::player::Id;
+use crate::{ActionError, Board, Card, Configuration, Error, StackId};
+
+/// Something which happened to a single played card while resolving a
+/// turn.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Outcome {
+    /// The player's card was placed on the given end of a stack.
+    Placed {
+        player: Id,
+        card: Card,
+        stack: StackId,
+        end: End,
+    },
+    /// The player took the cards of a stack, before placing their card on
+    /// it.
+    Taken {
+        player: Id,
+        stack: StackId,
+        cards: Vec<Card>,
+        reason: TakeReason,
+    },
+}
+
+impl From<Outcome> for GameEvent {
+    fn from(outcome: Outcome) -> Self {
+        match outcome {
+            Outcome::Placed {
+                player,
+                card,
+                stack,
+                end,
+            } => GameEvent::CardPlaced {
+                player,
+                card,
+                stack,
+                end,
+            },
+            Outcome::Taken {
+                player,
+                stack,
+                cards,
+                reason,
+            } => GameEvent::StackTaken {
+                player,
+                stack,
+                cards,
+                reason,
+            },
+        }
+    }
+}
+
+/// Resolves a turn where the given cards are played on the given board,
+/// returning the resulting board and what happened, in order.
+///
+/// Cards are placed lowest first, on the stack given by the placement
+/// `rule`, and a full stack is taken before the card is placed on it. When
+/// a card fits on no stack, `choose` is given the board as it stands, the
+/// card, and the id of the player who played it, and returns the stack the
+/// player takes. Nothing else is touched, so this can be used to ask what
+/// would happen if the cards were played.
+///
+/// # Errors
+///
+/// Returns `Err` if the placement rule can't place a card on the board,
+/// a card can't be pushed onto its stack, or `choose` returns a stack
+/// which isn't on the board.
+///
+/// # Examples
+///
+/// ```
+/// use take5::{resolve_turn, Board, Card, Configuration, Outcome, StackId};
+/// use take5::placement::Placement;
+///
+/// let config = Configuration::default();
+/// let deck = Card::deck(&config, |_| 1);
+/// let mut board = Board::new(2);
+/// board[StackId::A].push(deck[9].clone(), &config);
+/// board[StackId::B].push(deck[19].clone(), &config);
+///
+/// // Player 0 plays 5, and player 1 plays 25.
+/// let played = vec![(deck[24].clone(), 1), (deck[4].clone(), 0)];
+/// let (after, outcomes) = resolve_turn(&board, &played, &Placement::Standard, &config, |board, _, _| {
+///     // Take the stack with the fewest cards.
+///     board.ids().min_by_key(|&id| board[id].len()).unwrap()
+/// }).unwrap();
+///
+/// // The board played on is left alone.
+/// assert_eq!(board[StackId::A].len(), 1);
+/// assert_eq!(after[StackId::A].to_vec(), vec![deck[4].clone()]);
+/// assert_eq!(after[StackId::B].to_vec(), vec![deck[19].clone(), deck[24].clone()]);
+/// match outcomes[0] {
+///     Outcome::Taken { player, stack, .. } => assert_eq!((player, stack), (0, StackId::A)),
+///     _ => panic!("player 0 should take a stack"),
+/// }
+/// assert_eq!(outcomes.len(), 3);
+/// ```
+pub fn resolve_turn<F>(
+    board: &Board,
+    played: &[(Card, Id)],
+    rule: &dyn PlacementRule,
+    config: &Configuration,
+    mut choose: F,
+) -> Result<(Board, Vec<Outcome>), Error>
+where
+    F: FnMut(&Board, &Card, Id) -> StackId,
+{
+    let mut board = board.clone();
+    let mut played = played.to_vec();
+    played.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut outcomes = Vec::new();
+    for (card, id) in played {
+        // Find the stack `card` should be put on, and whether the player
+        // takes it first.
+        let (position, taken) = match rule.place(&board, &card)? {
+            Some(position) if board[position.stack].full(config) => {
+                (position, Some(TakeReason::Full))
+            }
+            Some(position) => (position, None),
+            // The player's card doesn't fit on any stack, and therefor the
+            // player must choose a stack to take.
+            None => {
+                let stack = choose(&board, &card, id);
+                if board.get(stack).is_none() {
+                    return Err(ActionError::NoSuchStack(id, stack).into());
+                }
+                let position = Position {
+                    stack,
+                    end: End::High,
+                };
+                (position, Some(TakeReason::Low))
+            }
+        };
+
+        let i = position.stack;
+        if let Some(reason) = taken {
+            outcomes.push(Outcome::Taken {
+                player: id,
+                stack: i,
+                cards: board[i].give(),
+                reason,
+            });
+        }
+        outcomes.push(Outcome::Placed {
+            player: id,
+            card: card.clone(),
+            stack: i,
+            end: position.end,
+        });
+        match position.end {
+            End::High => board[i].try_push(card, config)?,
+            End::Low => board[i].try_push_front(card, config)?,
+        }
+    }
+    Ok((board, outcomes))
+}
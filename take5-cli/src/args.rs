@@ -1,39 +1,59 @@
 use docopt::Docopt;
 use serde::Deserialize;
 
-#[cfg_attr(rustfmt, rustfmt_skip)]
+#[rustfmt::skip]
 #[cfg(feature = "ext")]
-const USAGE: &'static str = "
+const USAGE: &str = "
 The card game take5 (or 6nimmit!).
 
 Usage:
     take5 <players>
-    take5 [--conf=<file>, --bulls=<file>, --human] <players>
+    take5 [--conf=<file> --bulls=<file> --human --professional --tactical --seed=<n> --record=<file> --save=<file> --resume=<file> --json] <players>
     take5 [-hv]
 
 Options:
-    -h, --help      Show this screen.
-    -v, --version   Show the version of take5.
-    --conf=<file>   Global configurations for the game.
-    --bulls=<file>  Designates file as the configuration for bull values.
-                    This file should contian exactly `deck_size` comma separated bull values.
-    -H, --human     Use human players instead of AI players.
+    -h, --help       Show this screen.
+    -v, --version    Show the version of take5.
+    --conf=<file>    Global configurations for the game.
+    --bulls=<file>   Designates file as the configuration for bull values.
+                     This file should contian exactly `deck_size` comma separated bull values.
+    -H, --human      Use human players instead of AI players.
+    --professional   Play the professional variant, where cards may also be
+                     placed underneath a stack.
+    --tactical       Play the tactical variant, where only the cards which
+                     are dealt are in the deck.
+    --seed=<n>       Shuffle the deck with the given seed, to replay a game.
+    --record=<file>  Record every event of the game to the given file.
+    --save=<file>    Save the game to the given file before every turn.
+    --resume=<file>  Resume the game saved in the given file, with its saved
+                     configuration.
+    --json           Print the result of the game as JSON.
 ";
 
-#[cfg_attr(rustfmt, rustfmt_skip)]
+#[rustfmt::skip]
 #[cfg(not(feature = "ext"))]
-const USAGE: &'static str = "
+const USAGE: &str = "
 The card game take5 (or 6nimmit!).
 
 Usage:
     take5 <players>
-    take5 [--conf=<file>] <players>
+    take5 [--conf=<file> --professional --tactical --seed=<n> --record=<file> --save=<file> --resume=<file> --json] <players>
     take5 [-hv]
 
 Options:
-    -h, --help      Show this screen.
-    -v, --version   Show the version of take5.
-    --conf=<file>   Global configurations for the game.
+    -h, --help       Show this screen.
+    -v, --version    Show the version of take5.
+    --conf=<file>    Global configurations for the game.
+    --professional   Play the professional variant, where cards may also be
+                     placed underneath a stack.
+    --tactical       Play the tactical variant, where only the cards which
+                     are dealt are in the deck.
+    --seed=<n>       Shuffle the deck with the given seed, to replay a game.
+    --record=<file>  Record every event of the game to the given file.
+    --save=<file>    Save the game to the given file before every turn.
+    --resume=<file>  Resume the game saved in the given file, with its saved
+                     configuration.
+    --json           Print the result of the game as JSON.
 ";
 
 /// Command line arguments for the game.
@@ -49,6 +69,20 @@ pub struct Args {
     /// If the game should be played with human players.
     #[cfg(feature = "ext")]
     pub flag_human: bool,
+    /// If the game should be played with the professional variant.
+    pub flag_professional: bool,
+    /// If the game should be played with the tactical variant.
+    pub flag_tactical: bool,
+    /// Optional seed to shuffle the deck with.
+    pub flag_seed: Option<u64>,
+    /// Optional file to record the game to.
+    pub flag_record: Option<String>,
+    /// Optional file to save the game to.
+    pub flag_save: Option<String>,
+    /// Optional file to resume a saved game from.
+    pub flag_resume: Option<String>,
+    /// If the result of the game should be printed as JSON.
+    pub flag_json: bool,
 }
 
 impl Args {
@@ -73,5 +107,10 @@ impl Args {
         if self.arg_players < 2 || self.arg_players > 10 {
             panic!("Invalid number of players.");
         }
+        if self.flag_resume.is_some()
+            && (self.flag_conf.is_some() || self.flag_professional || self.flag_tactical)
+        {
+            panic!("A resumed game is played with its saved configuration, so it can't be given --conf, --professional or --tactical.");
+        }
     }
 }
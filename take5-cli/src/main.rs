@@ -7,39 +7,134 @@
 //!
 //! Usage:
 //!     take5 <players>
-//!     take5 [--conf=<file>, --bulls=<file>, --human] <players>
+//!     take5 [--conf=<file> --bulls=<file> --human --professional --tactical --seed=<n> --record=<file> --save=<file> --resume=<file> --json] <players>
 //!     take5 [-hv]
 //!
 //! Options:
-//!     -h, --help      Show this screen.
-//!     -v, --version   Show the version of take5.
-//!     --conf=<file>   Global configurations for the game.
-//!     --bulls=<file>  Designates file as the configuration for bull values.
-//!                     This file should contian exactly `deck_size` comma separated bull values.
-//!     -H, --human     Use human players instead of AI players.
+//!     -h, --help       Show this screen.
+//!     -v, --version    Show the version of take5.
+//!     --conf=<file>    Global configurations for the game.
+//!     --bulls=<file>   Designates file as the configuration for bull values.
+//!                      This file should contian exactly `deck_size` comma separated bull values.
+//!     -H, --human      Use human players instead of AI players.
+//!     --professional   Play the professional variant, where cards may also be
+//!                      placed underneath a stack.
+//!     --tactical       Play the tactical variant, where only the cards which
+//!                      are dealt are in the deck.
+//!     --seed=<n>       Shuffle the deck with the given seed, to replay a game.
+//!     --record=<file>  Record every event of the game to the given file.
+//!     --save=<file>    Save the game to the given file before every turn.
+//!     --resume=<file>  Resume the game saved in the given file, with its saved
+//!                      configuration.
+//!     --json           Print the result of the game as JSON.
 //! ```
 
 extern crate docopt;
 extern crate serde;
+extern crate serde_json;
 extern crate take5;
 #[cfg(feature = "ext")]
 extern crate take5_ext;
 
 use args::Args;
-use take5::{Configuration, Game};
+use std::fs::File;
+use std::process;
+use take5::placement::Placement;
+use take5::{Configuration, Game, Recorder, Status};
 
 fn main() {
     let args = Args::parse();
 
-    if let Some(ref f) = args.flag_conf {
-        unsafe {
-            Configuration::load(f);
+    let mut game = match args.flag_resume {
+        Some(ref path) => {
+            let snapshot = File::open(path)
+                .map_err(|e| e.to_string())
+                .and_then(|f| serde_json::from_reader(f).map_err(|e| e.to_string()))
+                .unwrap_or_else(|e| {
+                    eprintln!("could not read saved game: {}", e);
+                    process::exit(1);
+                });
+            Game::resume(snapshot, init::dealer(&args), init::players(&args)).unwrap_or_else(|e| {
+                eprintln!("could not resume game: {}", e);
+                process::exit(1);
+            })
+        }
+        None => Game::with_configuration(
+            configuration(&args),
+            init::dealer(&args),
+            init::players(&args),
+        ),
+    };
+    if let Some(ref path) = args.flag_record {
+        match File::create(path).and_then(Recorder::new) {
+            Ok(recorder) => game.observers.push(Box::new(recorder)),
+            Err(e) => {
+                eprintln!("could not record game: {}", e);
+                process::exit(1);
+            }
         }
     }
+    let result = match args.flag_save {
+        // Save the game before every turn, so it can be resumed.
+        Some(ref path) => loop {
+            let status = match game.step() {
+                Ok(status) => status,
+                Err(e) => break Err(e),
+            };
+            match status {
+                Status::NeedPlay(_) => save(&game, path),
+                Status::GameOver => break Ok(game.result()),
+                _ => {}
+            }
+            if let Err(e) = game.ask_players(&status) {
+                break Err(e);
+            }
+        },
+        None => game.try_run(),
+    };
+    let result = result.unwrap_or_else(|e| {
+        eprintln!("could not play game: {}", e);
+        process::exit(1);
+    });
+    if args.flag_json {
+        println!(
+            "{}",
+            serde_json::to_string(&result).expect("result failed to serialize to JSON")
+        );
+    } else {
+        println!("{}", result);
+    }
+}
 
-    let mut game = Game::new(init::dealer(&args), init::players(&args));
-    game.run();
-    game.report();
+fn configuration(args: &Args) -> Configuration {
+    let config = match args.flag_conf {
+        Some(ref f) => Configuration::from_path(f, args.arg_players as usize),
+        None => {
+            let config = Configuration::default();
+            config.validate(args.arg_players as usize).map(|_| config)
+        }
+    };
+    let mut config = config.unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+    if args.flag_professional {
+        config.placement = Placement::Professional;
+    }
+    if args.flag_tactical {
+        config.tactical = true;
+    }
+    config
+}
+
+fn save(game: &Game, path: &str) {
+    let saved = File::create(path)
+        .map_err(|e| e.to_string())
+        .and_then(|f| serde_json::to_writer(f, &game.snapshot()).map_err(|e| e.to_string()));
+    if let Err(e) = saved {
+        eprintln!("could not save game: {}", e);
+        process::exit(1);
+    }
 }
 
 #[cfg(feature = "ext")]
@@ -50,9 +145,11 @@ mod init {
     use take5_ext::{CustomDealer, StdinPlayer};
 
     pub fn dealer(args: &Args) -> Box<dyn Dealer> {
-        match args.flag_bulls {
-            None => Box::new(StandardDealer::default()) as Box<dyn Dealer>,
-            Some(ref s) => Box::new(CustomDealer::new(s)) as Box<dyn Dealer>,
+        match (&args.flag_bulls, args.flag_seed) {
+            (None, None) => Box::new(StandardDealer::default()) as Box<dyn Dealer>,
+            (None, Some(seed)) => Box::new(StandardDealer::seeded(seed)) as Box<dyn Dealer>,
+            (Some(s), None) => Box::new(CustomDealer::new(s)) as Box<dyn Dealer>,
+            (Some(s), Some(seed)) => Box::new(CustomDealer::seeded(s, seed)) as Box<dyn Dealer>,
         }
     }
 
@@ -75,8 +172,11 @@ mod init {
     use take5::dealer::{Dealer, StandardDealer};
     use take5::player::{AiPlayer, Player};
 
-    pub fn dealer(_: &Args) -> Box<Dealer> {
-        Box::new(StandardDealer::default()) as Box<Dealer>
+    pub fn dealer(args: &Args) -> Box<Dealer> {
+        match args.flag_seed {
+            None => Box::new(StandardDealer::default()) as Box<Dealer>,
+            Some(seed) => Box::new(StandardDealer::seeded(seed)) as Box<Dealer>,
+        }
     }
 
     pub fn players(args: &Args) -> Vec<Box<Player>> {
@@ -1,18 +1,22 @@
 use rand::{self, Rng};
+use std::fmt;
 use std::fs::File;
 use std::io::Read;
 use std::path::Path;
-use take5::{Card, Dealer};
+use take5::dealer::seeded_rng;
+use take5::{Card, Configuration, Dealer, Error};
 
 /// A dealer who reads bull values from a configuration file.
-#[derive(Debug, Default)]
 pub struct CustomDealer {
     deck: Vec<Card>,
     bull_vals: Vec<u8>,
+    rng: Box<dyn Rng + Send>,
+    seed: Option<u64>,
 }
 
 impl CustomDealer {
-    /// Creates a new CustomDealer using the config file with the given filename
+    /// Creates a new CustomDealer using the config file with the given
+    /// filename, shuffling with a random seed.
     ///
     /// # Examples
     /// ```
@@ -23,6 +27,42 @@ impl CustomDealer {
     pub fn new<R>(filename: R) -> CustomDealer
     where
         R: AsRef<Path>,
+    {
+        CustomDealer::seeded(filename, rand::thread_rng().gen())
+    }
+
+    /// Creates a new CustomDealer using the config file with the given
+    /// filename, shuffling with a generator built from the given seed.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate take5;
+    /// extern crate take5_ext;
+    ///
+    /// use take5::Dealer;
+    /// use take5_ext::CustomDealer;
+    ///
+    /// # fn main() {
+    /// let dealer = CustomDealer::seeded("../take5-cli/bull.conf", 42);
+    /// assert_eq!(dealer.seed(), Some(42));
+    /// # }
+    /// ```
+    pub fn seeded<R>(filename: R, seed: u64) -> CustomDealer
+    where
+        R: AsRef<Path>,
+    {
+        CustomDealer {
+            seed: Some(seed),
+            ..CustomDealer::with_rng(filename, seeded_rng(seed))
+        }
+    }
+
+    /// Creates a new CustomDealer using the config file with the given
+    /// filename, shuffling with the given generator.
+    pub fn with_rng<R, G>(filename: R, rng: G) -> CustomDealer
+    where
+        R: AsRef<Path>,
+        G: Rng + Send + 'static,
     {
         let mut file = File::open(filename).expect("File opening failed");
         let mut file_contents = String::new();
@@ -41,17 +81,45 @@ impl CustomDealer {
         CustomDealer {
             deck: Vec::new(),
             bull_vals: bull_values,
+            rng: Box::new(rng),
+            seed: None,
         }
     }
 }
 
+impl fmt::Debug for CustomDealer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.debug_struct("CustomDealer")
+            .field("deck", &self.deck)
+            .field("bull_vals", &self.bull_vals)
+            .field("seed", &self.seed)
+            .finish()
+    }
+}
+
 impl Dealer for CustomDealer {
-    fn open(&mut self) {
-        self.deck = Card::deck(|i| self.bull_vals[(i - 1) as usize]);
-        rand::thread_rng().shuffle(&mut self.deck)
+    fn open(&mut self, config: &Configuration) -> Result<(), Error> {
+        if self.bull_vals.len() < config.deck_size as usize {
+            return Err(Error::NoBull(self.bull_vals.len() as u16 + 1));
+        }
+        self.deck = Card::try_deck(config, |i| self.bull_vals[(i - 1) as usize])?;
+        self.rng.shuffle(&mut self.deck);
+        Ok(())
+    }
+
+    fn deck(&self) -> &[Card] {
+        &self.deck
+    }
+
+    fn restore(&mut self, deck: Vec<Card>) {
+        self.deck = deck;
+    }
+
+    fn deal(&mut self) -> Result<Card, Error> {
+        self.deck.pop().ok_or(Error::EmptyDeck)
     }
 
-    fn deal(&mut self) -> Card {
-        self.deck.pop().expect("dealer ran out of cards.")
+    fn seed(&self) -> Option<u64> {
+        self.seed
     }
 }
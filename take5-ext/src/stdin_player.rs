@@ -1,7 +1,7 @@
 use std::fmt;
 use std::io::{self, BufRead, Write};
-use take5::player::Id;
-use take5::{Board, Card, Player, StackId};
+use take5::player::{Id, PlayerError, PlayerView};
+use take5::{Board, Card, GameResult, Player, StackId};
 
 /// A player who makes decisions from STDIN.
 ///
@@ -25,7 +25,7 @@ impl StdinPlayer {
     /// ```
     pub fn new(id: Id) -> Self {
         StdinPlayer {
-            id: id,
+            id,
             hand: Vec::new(),
             pile: Vec::new(),
         }
@@ -49,6 +49,22 @@ impl Player for StdinPlayer {
         self.hand.push(card)
     }
 
+    fn discard(&mut self, card: &Card) {
+        self.hand.retain(|c| c != card);
+    }
+
+    fn start_round(&mut self, round: usize) {
+        println!("Player: {} starts round {}", self, round + 1);
+    }
+
+    fn end_round(&mut self, round: usize, scores: &[(Id, u32)]) {
+        println!("Player: {} ends round {}: {:?}", self, round + 1, scores);
+    }
+
+    fn end_game(&mut self, result: &GameResult) {
+        println!("Player: {} ends the game:\n{}", self, result);
+    }
+
     fn look_at_board(&mut self, board: &Board) {
         println!("Player: {} looks at board:\n{}", self, board);
     }
@@ -61,59 +77,56 @@ impl Player for StdinPlayer {
         println!("TAKEN: {:?}", taken);
     }
 
-    fn play(&mut self) -> Card {
-        print!("Your hand is: ");
+    fn play(&mut self, view: &PlayerView) -> Result<Card, PlayerError> {
+        print!("Scores are: ");
+        for (id, score) in view.scores() {
+            print!("{}: {}, ", id, score);
+        }
+        print!("\nYour hand is: ");
         for card in self.hand() {
             print!("{}, ", card);
         }
         print!("\nPlay a card: ");
         io::stdout().flush().expect("error writing to stdout.");
-        let stdin = io::stdin();
-        let line = stdin
-            .lock()
-            .lines()
-            .next()
-            .expect("no more lines in stdin")
-            .expect("error reading from stdin.");
+        let line = read_line()?;
         match line.parse() {
-            Ok(c) if c < self.hand.len() => self.hand.remove(c),
+            Ok(c) if c < self.hand.len() => Ok(self.hand.remove(c)),
             Ok(c) => {
                 println!(
                     "choice must be in range [0, {}], given {}.",
                     self.hand.len() - 1,
                     c
                 );
-                self.play()
+                self.play(view)
             }
             Err(e) => {
                 println!("{}, try again.", e);
-                self.play()
+                self.play(view)
             }
         }
     }
 
-    fn choose(&self) -> StackId {
-        print!("Your hand is: ");
-        for card in self.hand() {
-            print!("{}, ", card);
-        }
-        print!("\nChoose a stack (A, B, C, or D): ");
+    fn choose(
+        &self,
+        card: &Card,
+        board: &Board,
+        _view: &PlayerView,
+    ) -> Result<StackId, PlayerError> {
+        print!("Your card {} is lower than every stack.", card);
+        let stacks = board.len();
+        let ids = board
+            .ids()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        print!("\nChoose a stack ({}): ", ids);
         io::stdout().flush().expect("error writing to stdout.");
-        let stdin = io::stdin();
-        let line = stdin
-            .lock()
-            .lines()
-            .next()
-            .expect("no more lines in stdin")
-            .expect("error reading from stdin.");
-        match &line[..] {
-            "A" => StackId::A,
-            "B" => StackId::B,
-            "C" => StackId::C,
-            "D" => StackId::D,
-            n @ _ => {
-                println!("choice must be one of A, B, C, or D, given {}.", n);
-                self.choose()
+        let line = read_line()?;
+        match line.parse::<StackId>() {
+            Ok(id) if id.index() < stacks => Ok(id),
+            _ => {
+                println!("choice must be one of {}, given {}.", ids, line);
+                self.choose(card, board, _view)
             }
         }
     }
@@ -123,6 +136,17 @@ impl Player for StdinPlayer {
     }
 }
 
+/// Reads the next line from stdin, failing once stdin is closed.
+fn read_line() -> Result<String, PlayerError> {
+    let stdin = io::stdin();
+    let line = stdin.lock().lines().next();
+    match line {
+        Some(Ok(line)) => Ok(line),
+        Some(Err(e)) => Err(PlayerError::new(format!("error reading from stdin: {}", e))),
+        None => Err(PlayerError::new("no more lines in stdin.")),
+    }
+}
+
 impl fmt::Display for StdinPlayer {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         write!(f, "STDIN Player {}", self.id())
@@ -36,32 +36,32 @@ impl From<io::Error> for Error {
 }
 
 fn read_file<P: AsRef<Path>>(path: P) -> Result<String, Error> {
-    let mut file = try!(File::open(path));
+    let mut file = File::open(path)?;
     let mut buf = String::new();
-    try!(file.read_to_string(&mut buf));
+    file.read_to_string(&mut buf)?;
     Ok(buf)
 }
 
 fn open_test(id: &str) -> Result<(String, String), Error> {
     println!("Loading test: {}", id);
-    let requests = try!(read_file(format!("test/{}-in.json", id)));
-    let responses = try!(read_file(format!("test/{}-out.json", id)));
+    let requests = read_file(format!("test/{}-in.json", id))?;
+    let responses = read_file(format!("test/{}-out.json", id))?;
     Ok((requests, responses))
 }
 
 fn handle_client(id: &str, mut writer: TcpStream) -> Result<(), Error> {
-    let (test_requests, test_responses) = try!(open_test(id));
+    let (test_requests, test_responses) = open_test(id)?;
     let mut test_response_lines = test_responses.lines();
 
-    let reader = try!(writer.try_clone());
+    let reader = writer.try_clone()?;
     for line in test_requests.lines() {
         // Skip empty lines as "" isn't valid JSON.
-        if line == "" {
+        if line.is_empty() {
             continue;
         }
         match json::from_str::<RequestMessage>(line) {
             Ok(_) => {
-                try!(writer.write(line.as_bytes()));
+                writer.write_all(line.as_bytes())?;
                 // Return early if we are out of expected lines in the out file.
                 let expected_result = match test_response_lines.next() {
                     Some(e) => e,
@@ -72,7 +72,7 @@ fn handle_client(id: &str, mut writer: TcpStream) -> Result<(), Error> {
                     }
                 };
                 // Return early if the expected json is invalid.
-                let expected_json: Value = match json::from_str(&expected_result) {
+                let expected_json: Value = match json::from_str(expected_result) {
                     Ok(j) => j,
                     Err(_) => {
                         return Err(Error::InvalidTest("out.json file is invalid".to_string()))
@@ -81,7 +81,7 @@ fn handle_client(id: &str, mut writer: TcpStream) -> Result<(), Error> {
                 // Read 1 line, and ensure there are no more than one line in the buffer.
                 let mut actual_result = String::new();
                 let mut buf = BufReader::new(&reader);
-                try!(buf.read_line(&mut actual_result));
+                buf.read_line(&mut actual_result)?;
                 // Return early if the client sends back bad JSON.
                 let actual_json: Value = match json::from_str(&actual_result) {
                     Ok(j) => j,
@@ -97,7 +97,7 @@ fn handle_client(id: &str, mut writer: TcpStream) -> Result<(), Error> {
             }
             Err(_) => {
                 // Send bad stuff too, but the client should do nothing.
-                try!(writer.write(line.as_bytes()));
+                writer.write_all(line.as_bytes())?;
             }
         }
     }
@@ -69,14 +69,28 @@ impl Client {
     /// Parsing two tokens ahead isn't supported *easily* by our parser,
     /// so we opted for a `\n` seperator for the purposes of testing, as
     /// reading a `ResponseMessage` wasn't part of this assignment anyway.
-    pub fn new<S>(server: S, player: Box<Player>, newline: bool) -> Result<Self, Error>
+    pub fn new<S>(server: S, player: Box<dyn Player>, newline: bool) -> Result<Self, Error>
+    where
+        S: ToSocketAddrs,
+    {
+        Client::with_configuration(server, player, newline, Configuration::default())
+    }
+
+    /// Like `new`, but for a game played with the given configuration,
+    /// which hands are checked against, and players are shown.
+    pub fn with_configuration<S>(
+        server: S,
+        player: Box<dyn Player>,
+        newline: bool,
+        configuration: Configuration,
+    ) -> Result<Self, Error>
     where
         S: ToSocketAddrs,
     {
         Ok(Client {
-            player: PlayerProxy { player: player },
-            stream: try!(TcpStream::connect(server)),
-            newline: newline,
+            player: PlayerProxy::new(player, configuration),
+            stream: TcpStream::connect(server)?,
+            newline,
             current_state: GameState::StartingRound,
         })
     }
@@ -92,7 +106,7 @@ impl Client {
     ///
     /// This function can return `Err` if an underlying IO error occurs.
     pub fn start(&mut self) -> Result<(), Error> {
-        let mut reader = try!(self.stream.try_clone());
+        let reader = self.stream.try_clone()?;
         // for request in DeserializeJsonIter::new(&mut reader) {
         //     if let Ok(message) = request {
         //         try!(self.handle_message(message));
@@ -102,7 +116,7 @@ impl Client {
         for request in Deserializer::from_reader(&reader).into_iter::<RequestMessage>() {
             dbg!(&request);
             if let Ok(message) = request {
-                try!(self.handle_message(message));
+                self.handle_message(message)?;
             }
         }
         Ok(())
@@ -123,7 +137,7 @@ impl Client {
         let mut json = match self.current_state.next_state(&message) {
             Ok(next_state) => {
                 self.current_state = next_state;
-                let response = self.player.process_message(message);
+                let response = self.player.process_message(message)?;
                 json::to_string(&response).expect("message failed to serialize to JSON")
             }
             Err(_) => "false".to_string(),
@@ -132,7 +146,7 @@ impl Client {
             json.push('\n')
         }
         println!("Sending result: {:?}\n", json);
-        try!(self.stream.write(json.as_bytes()));
+        self.stream.write_all(json.as_bytes())?;
         Ok(())
     }
 }
@@ -1,17 +1,22 @@
 use std::fmt;
 use std::io;
+use take5::player::PlayerError;
 
 #[derive(Debug)]
 pub enum Error {
     GameState(String),
+    Hand(String),
     Io(io::Error),
+    Player(PlayerError),
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         match *self {
             Error::GameState(ref s) => write!(f, "game state error: {}", s),
+            Error::Hand(ref s) => write!(f, "hand error: {}", s),
             Error::Io(ref e) => e.fmt(f),
+            Error::Player(ref e) => write!(f, "player error: {}", e),
         }
     }
 }
@@ -21,3 +26,9 @@ impl From<io::Error> for Error {
         Error::Io(e)
     }
 }
+
+impl From<PlayerError> for Error {
+    fn from(e: PlayerError) -> Self {
+        Error::Player(e)
+    }
+}
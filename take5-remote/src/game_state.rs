@@ -60,18 +60,19 @@ mod tests {
     use super::*;
     use message::RequestMessage;
     use starting_hand::StartingHand;
+    use take5::Configuration;
     use wrapper::{Board, Card, Stack};
 
     fn start_round_msg() -> RequestMessage {
         let mut deck = Card::deck(|_| 2);
-        deck.split_off(10);
-        let hand = StartingHand::new(deck).unwrap();
+        deck.truncate(10);
+        let hand = StartingHand::new(deck, &Configuration::default()).unwrap();
         RequestMessage::StartRound(hand)
     }
 
     fn take_turn_msg() -> RequestMessage {
         let mut deck = Card::deck(|_| 2);
-        let stacks = [
+        let stacks = vec![
             Stack::new(vec![deck.remove(0)]),
             Stack::new(vec![deck.remove(0)]),
             Stack::new(vec![deck.remove(0)]),
@@ -83,7 +84,7 @@ mod tests {
 
     fn choose_msg() -> RequestMessage {
         let mut deck = Card::deck(|_| 2);
-        let stacks = [
+        let stacks = vec![
             Stack::new(vec![deck.remove(0)]),
             Stack::new(vec![deck.remove(0)]),
             Stack::new(vec![deck.remove(0)]),
@@ -140,7 +141,7 @@ mod tests {
 
     #[test]
     fn test_10_transitions_from_first_turn_to_starting_round() {
-        let ref mut state = GameState::FirstTurn;
+        let state = &mut GameState::FirstTurn;
         // 9 transitions into turns.
         for i in 0..9 {
             *state = state.next_state(&take_turn_msg()).unwrap();
@@ -176,14 +177,14 @@ mod tests {
 
     #[test]
     fn test_choose_on_last_turn() {
-        let ref mut state = GameState::Turns(9, false);
+        let state = &mut GameState::Turns(9, false);
         *state = state.next_state(&take_turn_msg()).unwrap();
         assert!(state.next_state(&choose_msg()).is_ok());
     }
 
     #[test]
     fn test_double_choose() {
-        let ref mut state = GameState::Turns(5, false);
+        let state = &mut GameState::Turns(5, false);
         *state = state.next_state(&choose_msg()).unwrap();
         assert!(state.next_state(&choose_msg()).is_err())
     }
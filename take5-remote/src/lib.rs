@@ -36,11 +36,9 @@ mod tests {
     fn test_card_decode_bad_values() {
         let json = "[0,2]";
         assert!(json::from_str::<Card>(json).is_err());
-        let json = "[105,2]";
+        let json = "[65536,2]";
         assert!(json::from_str::<Card>(json).is_err());
-        let json = "[1,1]";
-        assert!(json::from_str::<Card>(json).is_err());
-        let json = "[1,8]";
+        let json = "[1,-1]";
         assert!(json::from_str::<Card>(json).is_err());
     }
 
@@ -62,7 +60,7 @@ mod tests {
                             let json = "[\"start-round\",[[1,2],[2,2],[3,2],[4,2],[5,2],[6,2],[7,2],[8,2],[9,2],[10,2]]]";
                             let string = String::from(json);
                             for line in string.lines() {
-                                stream.write(line.as_bytes()).unwrap();
+                                stream.write_all(line.as_bytes()).unwrap();
                             }
                         });
                     }
@@ -1,46 +1,25 @@
 use super::RequestMessage;
-use serde::de::{Error, Visitor};
+use serde::de::{Error, IgnoredAny, SeqAccess, Visitor};
 use serde::{Deserialize, Deserializer};
+use std::fmt;
 
 struct RequestMessageVisitor;
 
-impl<'de> RequestMessageVisitor {
-    fn visit_start_round<V>(&mut self, mut visitor: V) -> Result<RequestMessage, D::Error>
+impl RequestMessageVisitor {
+    fn visit_second<'de, V, T>(&self, mut visitor: V, expected: &str) -> Result<T, V::Error>
     where
-        V: Visitor<'de>,
+        V: SeqAccess<'de>,
+        T: Deserialize<'de>,
     {
-        match try!(visitor.visit()) {
-            Some(cards) => {
-                try!(visitor.end());
-                Ok(RequestMessage::StartRound(cards))
-            }
-            None => Err(Error::syntax("second element must be valid cards")),
-        }
-    }
-
-    fn visit_take_turn<V>(&mut self, mut visitor: V) -> Result<RequestMessage, D::Error>
-    where
-        V: Visitor<'de>,
-    {
-        match try!(visitor.visit()) {
-            Some(board) => {
-                try!(visitor.end());
-                Ok(RequestMessage::TakeTurn(board))
-            }
-            None => Err(Error::syntax("second element must be valid board")),
-        }
-    }
-
-    fn visit_choose<V>(&mut self, mut visitor: V) -> Result<RequestMessage, D::Error>
-    where
-        V: Visitor<'de>,
-    {
-        match try!(visitor.visit()) {
-            Some(board) => {
-                try!(visitor.end());
-                Ok(RequestMessage::Choose(board))
-            }
-            None => Err(Error::syntax("second element must be valid board")),
+        match visitor.next_element()? {
+            Some(value) => match visitor.next_element::<IgnoredAny>()? {
+                Some(_) => Err(Error::custom("message must have exactly two elements")),
+                None => Ok(value),
+            },
+            None => Err(Error::custom(format!(
+                "second element must be valid {}",
+                expected
+            ))),
         }
     }
 }
@@ -48,28 +27,38 @@ impl<'de> RequestMessageVisitor {
 impl<'de> Visitor<'de> for RequestMessageVisitor {
     type Value = RequestMessage;
 
-    fn visit_seq<V>(&mut self, mut visitor: V) -> Result<Self::Value, D::Error>
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a request message array")
+    }
+
+    fn visit_seq<V>(self, mut visitor: V) -> Result<Self::Value, V::Error>
     where
-        V: Visitor<'de>,
+        V: SeqAccess<'de>,
     {
-        match try!(visitor.visit::<String>()) {
+        match visitor.next_element::<String>()? {
             Some(message_type) => match &message_type[..] {
-                "start-round" => self.visit_start_round(visitor),
-                "take-turn" => self.visit_take_turn(visitor),
-                "choose" => self.visit_choose(visitor),
-                _ => Err(Error::syntax("invalid message type")),
+                "start-round" => self
+                    .visit_second(visitor, "cards")
+                    .map(RequestMessage::StartRound),
+                "take-turn" => self
+                    .visit_second(visitor, "board")
+                    .map(RequestMessage::TakeTurn),
+                "choose" => self
+                    .visit_second(visitor, "board")
+                    .map(RequestMessage::Choose),
+                _ => Err(Error::custom("invalid message type")),
             },
-            None => Err(Error::syntax("first element must be a string")),
+            None => Err(Error::custom("first element must be a string")),
         }
     }
 }
 
 impl<'de> Deserialize<'de> for RequestMessage {
-    fn deserialize<D>(deserializer: &mut D) -> Result<Self, D::Error>
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        deserializer.visit(RequestMessageVisitor)
+        deserializer.deserialize_seq(RequestMessageVisitor)
     }
 }
 
@@ -77,7 +66,7 @@ impl<'de> Deserialize<'de> for RequestMessage {
 mod tests {
     use message::RequestMessage;
     use serde_json as json;
-    use take5::StackId;
+    use take5::{Configuration, StackId};
 
     #[test]
     fn test_request_decode_start_round() {
@@ -88,14 +77,19 @@ mod tests {
             RequestMessage::StartRound(cards) => {
                 assert_eq!(cards.take().len(), 10);
             }
-            _ => assert!(false),
+            _ => panic!("unexpected message"),
         }
     }
 
     #[test]
     fn test_request_decode_start_round_wrong_number_of_cards() {
         let json = "[\"start-round\",[[1,2],[2,2]]]";
-        assert!(json::from_str::<RequestMessage>(json).is_err());
+        match json::from_str::<RequestMessage>(json).unwrap() {
+            RequestMessage::StartRound(hand) => {
+                assert!(hand.check(&Configuration::default()).is_err());
+            }
+            _ => panic!("unexpected message"),
+        }
     }
 
     #[test]
@@ -106,7 +100,7 @@ mod tests {
             RequestMessage::TakeTurn(board) => {
                 assert_eq!(board[StackId::A].len(), 1);
             }
-            _ => assert!(false),
+            _ => panic!("unexpected message"),
         }
     }
 
@@ -126,7 +120,7 @@ mod tests {
             RequestMessage::Choose(board) => {
                 assert_eq!(board[StackId::A].len(), 1);
             }
-            _ => assert!(false),
+            _ => panic!("unexpected message"),
         }
     }
 
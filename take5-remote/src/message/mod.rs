@@ -1,10 +1,9 @@
-use serde::{Deserialize, Serialize};
 use starting_hand::StartingHand;
 use wrapper::{Board, Card, Stack};
 
 /// A request message is sent from the server to the client, indicating
 /// an action for the client to pass to the underlying player.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug)]
 pub enum RequestMessage {
     /// The first message of every round, containing the cards for
     /// a player's hand. This message always has enough cards for
@@ -25,7 +24,7 @@ pub enum RequestMessage {
 /// A message that is sent from the client in response to a valid
 /// request. Here "valid" means both that the request was a valid
 /// `RequestMessage`, and that it came at a valid time.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug)]
 pub enum ResponseMessage {
     /// The response to a new round starting. This message simply
     /// indicates to the server that the client accepted it's hand.
@@ -36,5 +35,5 @@ pub enum ResponseMessage {
     Choose(Stack),
 }
 
-// mod de;
-// mod ser;
+mod de;
+mod ser;
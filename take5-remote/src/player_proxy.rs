@@ -1,37 +1,105 @@
+use error::Error;
 use message::{RequestMessage, ResponseMessage};
 use starting_hand::StartingHand;
-use take5::Player;
+use take5::player::{PlayerError, PlayerView};
+use take5::{Configuration, Player};
 use wrapper::{Board, Card, Stack};
 
 pub struct PlayerProxy {
-    pub player: Box<Player>,
+    pub player: Box<dyn Player>,
+    configuration: Configuration,
+    round: usize,
+    played: Option<take5::Card>,
 }
 
 impl PlayerProxy {
-    pub fn process_message(&mut self, msg: RequestMessage) -> ResponseMessage {
-        match msg {
+    pub fn new(player: Box<dyn Player>, configuration: Configuration) -> Self {
+        PlayerProxy {
+            player,
+            configuration,
+            round: 0,
+            played: None,
+        }
+    }
+
+    pub fn process_message(&mut self, msg: RequestMessage) -> Result<ResponseMessage, Error> {
+        Ok(match msg {
             RequestMessage::StartRound(hand) => {
+                hand.check(&self.configuration)
+                    .map_err(|e| Error::Hand(e.to_string()))?;
                 self.start_round(hand);
                 ResponseMessage::StartRound
             }
-            RequestMessage::TakeTurn(board) => ResponseMessage::TakeTurn(self.take_turn(board)),
-            RequestMessage::Choose(board) => ResponseMessage::Choose(self.choose(board)),
-        }
+            RequestMessage::TakeTurn(board) => ResponseMessage::TakeTurn(self.take_turn(board)?),
+            RequestMessage::Choose(board) => ResponseMessage::Choose(self.choose(board)?),
+        })
     }
 
     fn start_round(&mut self, hand: StartingHand) {
+        // The server may have played cards for the player which they
+        // never heard of, so nothing held from past rounds is kept.
+        for card in self.player.hand().to_vec() {
+            self.player.discard(&card);
+        }
+        self.player.start_round(self.round);
+        self.round += 1;
         for card in hand.take() {
             self.player.draw(card.into());
         }
     }
 
-    fn take_turn(&mut self, board: Board) -> Card {
-        self.player.look_at_board(&*board);
-        self.player.play().into()
+    fn take_turn(&mut self, board: Board) -> Result<Card, PlayerError> {
+        self.player.look_at_board(&board);
+        let view = PlayerView::new(&*self.player, &board, &self.configuration);
+        let card = self.player.play(&view)?;
+        self.player.discard(&card);
+        // The server only asks us to choose for the card we just played.
+        self.played = Some(card.clone());
+        Ok(card.into())
+    }
+
+    fn choose(&mut self, board: Board) -> Result<Stack, PlayerError> {
+        self.player.look_at_board(&board);
+        let view = PlayerView::new(&*self.player, &board, &self.configuration);
+        let card = self
+            .played
+            .take()
+            .ok_or_else(|| PlayerError::new("no card was played to choose for."))?;
+        let stack = self.player.choose(&card, &board, &view)?;
+        Ok(board.take(stack).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use take5::player::AiPlayer;
+
+    fn start_round_msg(skip: usize) -> RequestMessage {
+        let mut deck = Card::deck(|_| 2).split_off(skip);
+        deck.truncate(10);
+        let hand = StartingHand::new(deck, &Configuration::default()).unwrap();
+        RequestMessage::StartRound(hand)
+    }
+
+    #[test]
+    fn test_start_round_drops_past_hand() {
+        let player = Box::new(AiPlayer::new(0));
+        let mut proxy = PlayerProxy::new(player, Configuration::default());
+        proxy.process_message(start_round_msg(0)).unwrap();
+        proxy.process_message(start_round_msg(10)).unwrap();
+        assert_eq!(10, proxy.player.hand().len());
+        assert!(proxy.player.hand().iter().all(|c| c.face() > 10));
     }
 
-    fn choose(&mut self, board: Board) -> Stack {
-        self.player.look_at_board(&*board);
-        board.take(self.player.choose()).into()
+    #[test]
+    fn test_start_round_configured() {
+        let config = Configuration {
+            turn_count: 5,
+            ..Configuration::default()
+        };
+        let mut proxy = PlayerProxy::new(Box::new(AiPlayer::new(0)), config);
+        assert!(proxy.process_message(start_round_msg(0)).is_err());
+        assert!(proxy.player.hand().is_empty());
     }
 }
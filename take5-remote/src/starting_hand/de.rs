@@ -1,5 +1,4 @@
 use super::StartingHand;
-use serde::de::Error;
 use serde::{Deserialize, Deserializer};
 
 impl<'de> Deserialize<'de> for StartingHand {
@@ -7,13 +6,8 @@ impl<'de> Deserialize<'de> for StartingHand {
     where
         D: Deserializer<'de>,
     {
-        // let cards = try!(deserializer.visit_seq(VecVisitor::new()));
-        let cards = Vec::deserialize(deserializer)?;
-        let len = cards.len();
-        match StartingHand::new(cards) {
-            Ok(hand) => Ok(hand),
-            // Err(_) => Err(Error::length_mismatch(len)),
-            Err(_) => Err(Error::custom(len)),
-        }
+        // The hand is checked against the game's configuration once it's
+        // received, see `PlayerProxy`.
+        Ok(StartingHand(Vec::deserialize(deserializer)?))
     }
 }
@@ -4,18 +4,20 @@ use std::fmt::{self, Display, Formatter};
 #[derive(Debug)]
 pub enum Error {
     CardLength(usize),
+    Card(String),
 }
 
 impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
             Error::CardLength(_) => "invalid number of cards in starting hand",
+            Error::Card(_) => "invalid card in starting hand",
         }
     }
 
-    fn cause(&self) -> Option<&error::Error> {
+    fn cause(&self) -> Option<&dyn error::Error> {
         match *self {
-            Error::CardLength(_) => None,
+            Error::CardLength(_) | Error::Card(_) => None,
         }
     }
 }
@@ -24,6 +26,7 @@ impl Display for Error {
     fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
         match *self {
             Error::CardLength(n) => write!(f, "invalid number of cards ({}) in starting hand", n),
+            Error::Card(ref reason) => write!(f, "invalid card in starting hand: {}", reason),
         }
     }
 }
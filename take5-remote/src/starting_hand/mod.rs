@@ -2,18 +2,27 @@ use serde::Serialize;
 use take5::Configuration;
 use wrapper::Card;
 
-/// A hand which must have `take5::Configuration::turn_count()` cards
-/// in it.
+/// A hand which must have `turn_count` cards in it, for the game's
+/// `take5::Configuration`. Decoding a hand only reads its cards, which
+/// are then checked against the game's configuration with `check`.
 #[derive(Debug, Serialize)]
 pub struct StartingHand(Vec<Card>);
 
 impl StartingHand {
-    pub fn new(cards: Vec<Card>) -> Result<Self, err::Error> {
-        if cards.len() != Configuration::turn_count() {
-            Err(err::Error::CardLength(cards.len()))
-        } else {
-            Ok(StartingHand(cards))
+    pub fn new(cards: Vec<Card>, config: &Configuration) -> Result<Self, err::Error> {
+        let hand = StartingHand(cards);
+        hand.check(config)?;
+        Ok(hand)
+    }
+
+    pub fn check(&self, config: &Configuration) -> Result<(), err::Error> {
+        if self.0.len() != config.turn_count {
+            return Err(err::Error::CardLength(self.0.len()));
         }
+        for card in &self.0 {
+            card.check(config).map_err(err::Error::Card)?;
+        }
+        Ok(())
     }
 
     pub fn take(self) -> Vec<Card> {
@@ -33,32 +42,44 @@ mod tests {
     #[test]
     fn test_new() {
         let mut cards = Card::deck(|_| 3);
-        cards.split_off(10);
-        let hand = StartingHand::new(cards);
+        cards.truncate(10);
+        let hand = StartingHand::new(cards, &Configuration::default());
         assert!(hand.is_ok());
     }
 
     #[test]
     fn test_new_too_few() {
         let mut cards = Card::deck(|_| 3);
-        cards.split_off(9);
-        let hand = StartingHand::new(cards);
+        cards.truncate(9);
+        let hand = StartingHand::new(cards, &Configuration::default());
         assert!(hand.is_err());
     }
 
     #[test]
     fn test_new_too_many() {
         let mut cards = Card::deck(|_| 3);
-        cards.split_off(11);
-        let hand = StartingHand::new(cards);
+        cards.truncate(11);
+        let hand = StartingHand::new(cards, &Configuration::default());
+        assert!(hand.is_err());
+    }
+
+    #[test]
+    fn test_new_card_not_in_deck() {
+        let mut cards = Card::deck(|_| 3);
+        cards.truncate(10);
+        let config = Configuration {
+            deck_size: 5,
+            ..Configuration::default()
+        };
+        let hand = StartingHand::new(cards, &config);
         assert!(hand.is_err());
     }
 
     #[test]
     fn test_encode_starting_hand() {
         let mut cards = Card::deck(|_| 2);
-        cards.split_off(10);
-        let hand = StartingHand::new(cards).unwrap();
+        cards.truncate(10);
+        let hand = StartingHand::new(cards, &Configuration::default()).unwrap();
         let json = json::to_string(&hand).unwrap();
         assert_eq!(
             "[[1,2],[2,2],[3,2],[4,2],[5,2],[6,2],[7,2],[8,2],[9,2],[10,2]]",
@@ -76,14 +97,25 @@ mod tests {
     #[test]
     fn test_decode_starting_hand_too_few() {
         let json = "[[1,2],[2,2],[3,2],[4,2],[5,2],[6,2],[7,2],[8,2],[9,2]]";
-        let result = json::from_str::<StartingHand>(json);
-        assert!(result.is_err());
+        let hand = json::from_str::<StartingHand>(json).unwrap();
+        assert!(hand.check(&Configuration::default()).is_err());
     }
 
     #[test]
     fn test_decode_starting_hand_too_many() {
         let json = "[[1,2],[2,2],[3,2],[4,2],[5,2],[6,2],[7,2],[8,2],[9,2],[10,2],[11,2]]";
-        let result = json::from_str::<StartingHand>(json);
-        assert!(result.is_err());
+        let hand = json::from_str::<StartingHand>(json).unwrap();
+        assert!(hand.check(&Configuration::default()).is_err());
+    }
+
+    #[test]
+    fn test_decode_starting_hand_configured() {
+        let json = "[[1,2],[2,2],[3,2],[4,2],[5,2]]";
+        let hand = json::from_str::<StartingHand>(json).unwrap();
+        let config = Configuration {
+            turn_count: 5,
+            ..Configuration::default()
+        };
+        assert!(hand.check(&config).is_ok());
     }
 }
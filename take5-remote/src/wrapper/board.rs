@@ -1,13 +1,27 @@
+use serde::de::{Deserialize, Deserializer, Error};
 use take5;
 use wrapper::Stack;
 
 create_wrapper!(Board);
 
+impl<'de> Deserialize<'de> for Board {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let stacks = Vec::<Stack>::deserialize(deserializer)?;
+        if stacks.is_empty() {
+            return Err(Error::custom("boards must have at least one stack"));
+        }
+        Ok(Board::new(stacks))
+    }
+}
+
 impl Board {
-    pub fn new(stacks: [Stack; 4]) -> Self {
-        let mut wrapped = take5::Board::default();
-        for (stack, id) in stacks.into_iter().zip(take5::StackId::all().into_iter()) {
-            wrapped[*id].extend_from_slice(stack);
+    pub fn new(stacks: Vec<Stack>) -> Self {
+        let mut wrapped = take5::Board::new(stacks.len());
+        for (i, stack) in stacks.into_iter().enumerate() {
+            wrapped[take5::StackId::new(i)] = stack.into();
         }
         Board(wrapped)
     }
@@ -25,7 +39,7 @@ mod tests {
 
     fn board() -> Board {
         let mut deck = Card::deck(|_| 2);
-        let stacks = [
+        let stacks = vec![
             Stack::new(vec![deck.remove(0)]),
             Stack::new(vec![deck.remove(0)]),
             Stack::new(vec![deck.remove(0)]),
@@ -34,48 +48,48 @@ mod tests {
         Board::new(stacks)
     }
 
-    // #[test]
-    // fn test_decode() {
-    //     let json = "[[[4,2],[5,2]],[[9,2],[7,2]],[[10,2],[11,2]],[[12,2],[13,2]]]";
-    //     let board = json::from_str::<Board>(json).unwrap();
-    //     assert_eq!(4, board[take5::StackId::A].bulls())
-    // }
+    #[test]
+    fn test_decode() {
+        let json = "[[[4,2],[5,2]],[[9,2],[7,2]],[[10,2],[11,2]],[[12,2],[13,2]]]";
+        let board = json::from_str::<Board>(json).unwrap();
+        assert_eq!(4, board[take5::StackId::A].bulls())
+    }
 
-    // #[test]
-    // fn test_decode_small() {
-    //     let json = "[[[4,2],[5,2]],[[9,2],[7,2]]]";
-    //     let board = json::from_str::<Board>(json);
-    //     assert!(board.is_err());
-    // }
+    #[test]
+    fn test_decode_small() {
+        let json = "[[[4,2],[5,2]],[[9,2],[7,2]]]";
+        let board = json::from_str::<Board>(json).unwrap();
+        assert_eq!(2, board.len());
+    }
 
-    // #[test]
-    // fn test_decode_large() {
-    //     let json =
-    //         "[[[4,2],[5,2]],[[9,2],[7,2]],[[4,2],[5,2]],[[4,2],[5,2]],[[4,2],[5,2]],[[4,2],[5,2]]]";
-    //     let board = json::from_str::<Board>(json);
-    //     assert!(board.is_err());
-    // }
+    #[test]
+    fn test_decode_large() {
+        let json =
+            "[[[4,2],[5,2]],[[9,2],[7,2]],[[4,2],[5,2]],[[4,2],[5,2]],[[4,2],[5,2]],[[4,2],[5,2]]]";
+        let board = json::from_str::<Board>(json).unwrap();
+        assert_eq!(6, board.len());
+    }
 
-    // #[test]
-    // fn test_decode_empty() {
-    //     let json = "[]";
-    //     let board = json::from_str::<Board>(json);
-    //     assert!(board.is_err());
-    // }
+    #[test]
+    fn test_decode_empty() {
+        let json = "[]";
+        let board = json::from_str::<Board>(json);
+        assert!(board.is_err());
+    }
 
-    // #[test]
-    // fn test_decode_bad_card() {
-    //     let json = "[[[4,2],[5,2],[6,4]],[[9,2],[4,'5'][7,2]],[[10,2],[11,2]],[[12,2],[13,2]]]";
-    //     let board = json::from_str::<Board>(json);
-    //     assert!(board.is_err());
-    // }
+    #[test]
+    fn test_decode_bad_card() {
+        let json = "[[[4,2],[5,2],[6,4]],[[9,2],[4,'5'][7,2]],[[10,2],[11,2]],[[12,2],[13,2]]]";
+        let board = json::from_str::<Board>(json);
+        assert!(board.is_err());
+    }
 
-    // #[test]
-    // fn test_decode_bad_stack() {
-    //     let json = "[[[4,2],[5,2],'hello'],[[9,2],[4,'5'][7,2]],[[10,2],[11,2]],[[12,2],[13,2]]]";
-    //     let board = json::from_str::<Board>(json);
-    //     assert!(board.is_err());
-    // }
+    #[test]
+    fn test_decode_bad_stack() {
+        let json = "[[[4,2],[5,2],'hello'],[[9,2],[4,'5'][7,2]],[[10,2],[11,2]],[[12,2],[13,2]]]";
+        let board = json::from_str::<Board>(json);
+        assert!(board.is_err());
+    }
 
     #[test]
     fn test_encode() {
@@ -1,7 +1,51 @@
+use serde::de::{Deserialize, Deserializer, Error};
 use take5;
+use take5::Configuration;
 
 create_wrapper!(Card);
 
+impl Card {
+    /// Checks the card could be in a deck of the given configuration,
+    /// returning why not otherwise.
+    pub fn check(&self, config: &Configuration) -> Result<(), String> {
+        if self.face() > config.deck_size {
+            return Err(format!(
+                "face value must be in range [1, {}], was {}",
+                config.deck_size,
+                self.face()
+            ));
+        }
+        let (lower, upper) = config.bull_range;
+        if self.bull() < lower || self.bull() > upper {
+            return Err(format!(
+                "bull value must be in range [{}, {}], was {}",
+                lower,
+                upper,
+                self.bull()
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl<'de> Deserialize<'de> for Card {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Any face a `u16` holds may be in a large enough deck, so the
+        // rest is checked against the game's configuration, see `check`.
+        let card = take5::Card::deserialize(deserializer)?;
+        if card.face() < 1 {
+            return Err(Error::custom(format!(
+                "face value must be at least 1, was {}",
+                card.face()
+            )));
+        }
+        Ok(Card(card))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -11,9 +55,12 @@ mod tests {
     impl Card {
         pub fn deck<F>(f: F) -> Vec<Card>
         where
-            F: Fn(u8) -> u8,
+            F: Fn(u16) -> u8,
         {
-            take5::Card::deck(f).into_iter().map(|c| c.into()).collect()
+            take5::Card::deck(&take5::Configuration::default(), f)
+                .into_iter()
+                .map(|c| c.into())
+                .collect()
         }
     }
 
@@ -44,21 +91,49 @@ mod tests {
         let json = "[0,2]";
         let card = json::from_str::<Card>(json);
         assert!(card.is_err());
-        let json = "[1,1]";
-        let card = json::from_str::<Card>(json);
-        assert!(card.is_err());
     }
 
     #[test]
     fn test_decode_too_large_numbers() {
-        let json = "[105,2]";
+        let json = "[65536,2]";
         let card = json::from_str::<Card>(json);
         assert!(card.is_err());
-        let json = "[1,8]";
+        let json = "[1,256]";
         let card = json::from_str::<Card>(json);
         assert!(card.is_err());
     }
 
+    #[test]
+    fn test_decode_large_deck() {
+        let json = "[300,2]";
+        let card = json::from_str::<Card>(json).unwrap();
+        assert_eq!(300, card.face());
+    }
+
+    #[test]
+    fn test_check() {
+        let config = Configuration::default();
+        let card: Card = take5::Card::new(104, 7).into();
+        assert!(card.check(&config).is_ok());
+        let card: Card = take5::Card::new(105, 2).into();
+        assert!(card.check(&config).is_err());
+        let card: Card = take5::Card::new(1, 8).into();
+        assert!(card.check(&config).is_err());
+    }
+
+    #[test]
+    fn test_check_large_deck() {
+        let config = Configuration {
+            deck_size: 300,
+            bull_range: (2, 7),
+            ..Configuration::default()
+        };
+        let card: Card = take5::Card::new(300, 2).into();
+        assert!(card.check(&config).is_ok());
+        let card: Card = take5::Card::new(1, 1).into();
+        assert!(card.check(&config).is_err());
+    }
+
     #[test]
     fn test_decode_not_an_array() {
         let json = "{\"face\": 3, \"bull\": 2}";
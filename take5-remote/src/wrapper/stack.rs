@@ -1,13 +1,31 @@
+use serde::de::{Deserialize, Deserializer, Error};
 use take5;
+use take5::Configuration;
 use wrapper::Card;
 
 create_wrapper!(Stack);
 
+impl<'de> Deserialize<'de> for Stack {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let cards = Vec::<Card>::deserialize(deserializer)?;
+        if cards.is_empty() {
+            return Err(Error::custom("stacks must have at least one card"));
+        }
+        let mut wrapped = take5::Stack::default();
+        wrapped.extend(cards.into_iter().map(Into::into));
+        Ok(Stack(wrapped))
+    }
+}
+
 impl Stack {
     pub fn new(cards: Vec<Card>) -> Self {
+        let config = Configuration::default();
         let mut wrapped = take5::Stack::default();
         for card in cards {
-            wrapped.push(card.into());
+            wrapped.push(card.into(), &config);
         }
         Stack(wrapped)
     }